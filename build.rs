@@ -0,0 +1,43 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Record the git hash and mwalib version at build time, for the provenance
+/// of dumps.
+use std::process::Command;
+
+/// The resolved version of mwalib, from the `[[package]]` entries of
+/// Cargo.lock.
+fn mwalib_version() -> Option<String> {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")?;
+    let lock =
+        std::fs::read_to_string(std::path::Path::new(&manifest_dir).join("Cargo.lock")).ok()?;
+    let mut lines = lock.lines().map(str::trim);
+    lines.find(|&line| line == "name = \"mwalib\"")?;
+    lines
+        .next()?
+        .strip_prefix("version = \"")?
+        .strip_suffix('"')
+        .map(str::to_string)
+}
+
+fn main() {
+    let git_hash = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIT_HASH={}", git_hash);
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/index");
+
+    let mwalib_version = mwalib_version().unwrap_or_else(|| {
+        println!("cargo:warning=couldn't find the mwalib version in Cargo.lock, so dumps will record it as unknown");
+        "unknown".to_string()
+    });
+    println!("cargo:rustc-env=MWALIB_VERSION={}", mwalib_version);
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+}
@@ -0,0 +1,181 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given a metafits file, tabulate the geometry of every baseline so that
+/// baseline indices in dumps can be joined against it.
+use crate::ScratchpadError;
+use mwalib::{Antenna, Baseline, MetafitsContext};
+use serde::Serialize;
+use std::io::Write;
+use structopt::StructOpt;
+
+/// A baseline, with the separation of its antennas from the metafits tile positions.
+#[derive(Serialize, Debug, Clone)]
+pub struct BaselineGeometry {
+    pub baseline: usize,
+    pub ant1: usize,
+    pub ant2: usize,
+    pub ant1_name: String,
+    pub ant2_name: String,
+    pub ant1_tile_id: u32,
+    pub ant2_tile_id: u32,
+    /// Position of ant2 relative to ant1
+    pub east_m: f64,
+    pub north_m: f64,
+    pub up_m: f64,
+    pub length_m: f64,
+}
+
+/// Compute the geometry of each of the context's baselines, in baseline index order.
+pub fn baseline_geometries(context: &MetafitsContext) -> Vec<BaselineGeometry> {
+    geometries(&context.antennas, &context.baselines)
+}
+
+fn geometries(antennas: &[Antenna], baselines: &[Baseline]) -> Vec<BaselineGeometry> {
+    baselines
+        .iter()
+        .enumerate()
+        .map(|(baseline_index, baseline)| {
+            let ant1 = &antennas[baseline.ant1_index];
+            let ant2 = &antennas[baseline.ant2_index];
+            let east_m = ant2.rfinput_x.east_m - ant1.rfinput_x.east_m;
+            let north_m = ant2.rfinput_x.north_m - ant1.rfinput_x.north_m;
+            let up_m = ant2.rfinput_x.height_m - ant1.rfinput_x.height_m;
+            BaselineGeometry {
+                baseline: baseline_index,
+                ant1: baseline.ant1_index,
+                ant2: baseline.ant2_index,
+                ant1_name: ant1.tile_name.clone(),
+                ant2_name: ant2.tile_name.clone(),
+                ant1_tile_id: ant1.tile_id,
+                ant2_tile_id: ant2.tile_id,
+                east_m,
+                north_m,
+                up_m,
+                length_m: (east_m * east_m + north_m * north_m + up_m * up_m).sqrt(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-baselines", author)]
+pub struct BaselinesOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Print JSON instead of CSV
+    #[structopt(short, long)]
+    pub json: bool,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn dump_baselines<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    json: bool,
+) -> Result<(), ScratchpadError> {
+    let context = MetafitsContext::new(metafits)?;
+    let baselines = baseline_geometries(&context);
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+
+    if json {
+        writeln!(&mut out, "{}", serde_json::to_string_pretty(&baselines)?)?;
+        return Ok(());
+    }
+
+    writeln!(
+        &mut out,
+        "baseline,ant1,ant2,ant1_name,ant2_name,ant1_tile_id,ant2_tile_id,east_m,north_m,up_m,length_m"
+    )?;
+    for baseline in &baselines {
+        writeln!(
+            &mut out,
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            baseline.baseline,
+            baseline.ant1,
+            baseline.ant2,
+            baseline.ant1_name,
+            baseline.ant2_name,
+            baseline.ant1_tile_id,
+            baseline.ant2_tile_id,
+            baseline.east_m,
+            baseline.north_m,
+            baseline.up_m,
+            baseline.length_m,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mwalib::{Pol, RFInput};
+
+    fn test_rf_input(pol: Pol, east_m: f64, north_m: f64, height_m: f64) -> RFInput {
+        RFInput {
+            input: 0,
+            ant: 0,
+            tile_id: 0,
+            tile_name: String::new(),
+            pol,
+            electrical_length_m: 0.,
+            north_m,
+            east_m,
+            height_m,
+            vcs_order: 0,
+            subfile_order: 0,
+            flagged: false,
+            digital_gains: vec![],
+            dipole_gains: vec![],
+            dipole_delays: vec![],
+            rec_number: 1,
+            rec_slot_number: 1,
+        }
+    }
+
+    fn test_antenna(tile_id: u32, east_m: f64, north_m: f64, height_m: f64) -> Antenna {
+        Antenna {
+            ant: 0,
+            tile_id,
+            tile_name: format!("Tile{:03}", tile_id),
+            rfinput_x: test_rf_input(Pol::X, east_m, north_m, height_m),
+            rfinput_y: test_rf_input(Pol::Y, east_m, north_m, height_m),
+        }
+    }
+
+    #[test]
+    fn test_geometries() {
+        let antennas = [
+            test_antenna(11, 10., 20., 377.),
+            test_antenna(12, 13., 24., 377.),
+        ];
+        let baselines = [
+            Baseline {
+                ant1_index: 0,
+                ant2_index: 0,
+            },
+            Baseline {
+                ant1_index: 0,
+                ant2_index: 1,
+            },
+        ];
+        let geometries = geometries(&antennas, &baselines);
+        assert_eq!(geometries.len(), 2);
+        assert_eq!(geometries[0].length_m, 0.);
+        let cross = &geometries[1];
+        assert_eq!(cross.baseline, 1);
+        assert_eq!((cross.ant1, cross.ant2), (0, 1));
+        assert_eq!(cross.ant2_name, "Tile012");
+        assert_eq!(cross.ant2_tile_id, 12);
+        // Position of ant2 relative to ant1
+        assert_eq!((cross.east_m, cross.north_m, cross.up_m), (3., 4., 0.));
+        assert_eq!(cross.length_m, 5.);
+    }
+}
@@ -0,0 +1,202 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given an observation's data, map coarse and fine channel indices in dumps
+/// to sky frequencies and the gpubox files which back them.
+use crate::gpubox::GpuboxFile;
+use crate::ScratchpadError;
+use mwalib::{CoarseChannel, CorrelatorContext, MetafitsContext};
+use serde::Serialize;
+use std::io::Write;
+use structopt::StructOpt;
+
+/// The centre frequency of each fine channel within a coarse channel. Fine
+/// channel `n / 2` is centred on the coarse channel centre.
+pub fn fine_chan_freqs_hz(
+    coarse_chan: &CoarseChannel,
+    metafits_context: &MetafitsContext,
+) -> Vec<f64> {
+    fine_chan_centres_hz(
+        coarse_chan.chan_centre_hz as f64,
+        metafits_context.num_corr_fine_chans_per_coarse,
+        metafits_context.corr_fine_chan_width_hz as f64,
+    )
+}
+
+fn fine_chan_centres_hz(
+    coarse_chan_centre_hz: f64,
+    num_fine_chans: usize,
+    fine_chan_width_hz: f64,
+) -> Vec<f64> {
+    (0..num_fine_chans)
+        .map(|fine_chan_index| {
+            coarse_chan_centre_hz
+                + (fine_chan_index as f64 - (num_fine_chans / 2) as f64) * fine_chan_width_hz
+        })
+        .collect()
+}
+
+/// A coarse channel expected from the metafits, and where to find it.
+#[derive(Serialize, Debug)]
+pub struct ChannelInfo {
+    /// Index into the context's coarse channels (the `coarse_chan` column of
+    /// dumps), or `None` if no gpubox file provides this channel.
+    pub coarse_chan: Option<usize>,
+    pub corr_chan_number: usize,
+    pub rec_chan_number: usize,
+    pub gpubox_number: usize,
+    pub chan_width_hz: u32,
+    pub chan_start_hz: u32,
+    pub chan_centre_hz: u32,
+    pub chan_end_hz: u32,
+    pub fine_chan_freqs_hz: Vec<f64>,
+    pub gpubox_files: Vec<String>,
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-channels", author)]
+pub struct ChannelsOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Output one CSV row per fine channel instead of per coarse channel
+    #[structopt(short, long)]
+    pub fine_chans: bool,
+
+    /// Print JSON instead of CSV
+    #[structopt(short, long)]
+    pub json: bool,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn dump_channels<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    files: &[T],
+    fine_chans: bool,
+    json: bool,
+) -> Result<(), ScratchpadError> {
+    let context = CorrelatorContext::new(metafits, files)?;
+    let gpubox_files = files
+        .iter()
+        .map(GpuboxFile::from_path)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let channels: Vec<ChannelInfo> = context
+        .metafits_context
+        .get_expected_coarse_channels(context.corr_version)?
+        .iter()
+        .map(|coarse_chan| ChannelInfo {
+            coarse_chan: context
+                .coarse_chans
+                .iter()
+                .position(|provided| provided.rec_chan_number == coarse_chan.rec_chan_number),
+            corr_chan_number: coarse_chan.corr_chan_number,
+            rec_chan_number: coarse_chan.rec_chan_number,
+            gpubox_number: coarse_chan.gpubox_number,
+            chan_width_hz: coarse_chan.chan_width_hz,
+            chan_start_hz: coarse_chan.chan_start_hz,
+            chan_centre_hz: coarse_chan.chan_centre_hz,
+            chan_end_hz: coarse_chan.chan_end_hz,
+            fine_chan_freqs_hz: fine_chan_freqs_hz(coarse_chan, &context.metafits_context),
+            gpubox_files: gpubox_files
+                .iter()
+                .filter(|gpubox_file| gpubox_file.channel_identifier == coarse_chan.gpubox_number)
+                .map(|gpubox_file| gpubox_file.filename.clone())
+                .collect(),
+        })
+        .collect();
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+
+    if json {
+        writeln!(&mut out, "{}", serde_json::to_string_pretty(&channels)?)?;
+        return Ok(());
+    }
+
+    let coarse_chan_column = |channel: &ChannelInfo| match channel.coarse_chan {
+        Some(coarse_chan_index) => coarse_chan_index.to_string(),
+        None => "".to_string(),
+    };
+
+    if fine_chans {
+        writeln!(
+            &mut out,
+            "coarse_chan,rec_chan_number,gpubox_number,fine_chan,freq_hz"
+        )?;
+        for channel in &channels {
+            for (fine_chan_index, freq_hz) in channel.fine_chan_freqs_hz.iter().enumerate() {
+                writeln!(
+                    &mut out,
+                    "{},{},{},{},{}",
+                    coarse_chan_column(channel),
+                    channel.rec_chan_number,
+                    channel.gpubox_number,
+                    fine_chan_index,
+                    freq_hz,
+                )?;
+            }
+        }
+        return Ok(());
+    }
+
+    writeln!(
+        &mut out,
+        "coarse_chan,corr_chan_number,rec_chan_number,gpubox_number,chan_width_hz,chan_start_hz,chan_centre_hz,chan_end_hz,gpubox_files"
+    )?;
+    for channel in &channels {
+        writeln!(
+            &mut out,
+            "{},{},{},{},{},{},{},{},{}",
+            coarse_chan_column(channel),
+            channel.corr_chan_number,
+            channel.rec_chan_number,
+            channel.gpubox_number,
+            channel.chan_width_hz,
+            channel.chan_start_hz,
+            channel.chan_centre_hz,
+            channel.chan_end_hz,
+            channel.gpubox_files.join(";"),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fine_chan_centres_hz_legacy() {
+        // 1065880128: legacy correlator, 40 kHz fine channels, receiver channel 109 centred on
+        // 139.52 MHz
+        let freqs_hz = fine_chan_centres_hz(139_520_000., 32, 40_000.);
+        assert_eq!(freqs_hz.len(), 32);
+        assert_eq!(freqs_hz[0], 138_880_000.);
+        assert_eq!(freqs_hz[16], 139_520_000.);
+        assert_eq!(freqs_hz[31], 140_120_000.);
+    }
+
+    #[test]
+    fn test_fine_chan_centres_hz_10khz() {
+        // Receiver channel 117 (149.76 MHz) at the MWAX default of 10 kHz fine channels
+        let freqs_hz = fine_chan_centres_hz(149_760_000., 128, 10_000.);
+        assert_eq!(freqs_hz.len(), 128);
+        assert_eq!(freqs_hz[0], 149_120_000.);
+        assert_eq!(freqs_hz[64], 149_760_000.);
+        assert_eq!(freqs_hz[127], 150_390_000.);
+    }
+}
@@ -0,0 +1,355 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Load subcommand options from a TOML or YAML config file given with
+/// `--config`. Options from the file are inserted into the command line just
+/// after the subcommand, so clap validates them like any other, and options
+/// given on the command line override them.
+use crate::ScratchpadError;
+use serde_json::Value;
+use std::ffi::OsString;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use structopt::clap::{App, AppSettings, ArgMatches};
+
+/// The config key for the gpubox files, which are positional.
+pub const FILES_KEY: &str = "files";
+/// The name of the positional gpubox files argument.
+const FILES_ARG: &str = "GPUBOX FILE";
+
+/// Find the path given to a long option, e.g. `--config`, before parsing.
+pub fn find_path_option(args: &[OsString], long: &str) -> Option<PathBuf> {
+    let prefix = format!("{}=", long);
+    args.iter().enumerate().find_map(|(index, arg)| {
+        let arg = arg.to_str()?;
+        if arg == long {
+            args.get(index + 1).map(PathBuf::from)
+        } else {
+            arg.strip_prefix(prefix.as_str()).map(PathBuf::from)
+        }
+    })
+}
+
+/// Read a config file, as TOML unless its extension is `.yaml` or `.yml`,
+/// into a map of option names to values.
+pub fn read_config<T: AsRef<Path>>(
+    path: &T,
+) -> Result<serde_json::Map<String, Value>, ScratchpadError> {
+    let path = path.as_ref();
+    let config_error = |reason: String| ScratchpadError::Config {
+        path: path.display().to_string(),
+        reason,
+    };
+    let contents = read_to_string(path)?;
+    let config: Value = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("yaml") | Some("yml") => {
+            serde_yaml::from_str(&contents).map_err(|err| config_error(err.to_string()))?
+        }
+        _ => toml::from_str(&contents).map_err(|err| config_error(err.to_string()))?,
+    };
+    match config {
+        Value::Object(options) => Ok(options),
+        Value::Null => Ok(serde_json::Map::new()),
+        _ => Err(config_error("expected a table of options".to_string())),
+    }
+}
+
+/// The command line arguments for one option from a config file, e.g.
+/// `vis_radix = 16` becomes `--vis-radix=16`, `uvw = true` becomes `--uvw`
+/// and `mwaf = ["a.mwaf", "b.mwaf"]` becomes `--mwaf=a.mwaf --mwaf=b.mwaf`.
+fn option_args(key: &str, value: &Value) -> Result<Vec<OsString>, String> {
+    let values = match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    let mut args = vec![];
+    for value in values {
+        let value = match value {
+            Value::Null | Value::Bool(false) => continue,
+            Value::Bool(true) if key != FILES_KEY => None,
+            Value::String(string) => Some(string.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            _ => return Err(format!("{} can't be given as {}", key, value)),
+        };
+        args.push(match (key, value) {
+            (FILES_KEY, Some(value)) => OsString::from(value),
+            (_, Some(value)) => OsString::from(format!("--{}={}", key.replace('_', "-"), value)),
+            (_, None) => OsString::from(format!("--{}", key.replace('_', "-"))),
+        });
+    }
+    Ok(args)
+}
+
+/// The name clap knows an option by.
+fn arg_name(key: &str) -> String {
+    match key {
+        FILES_KEY => FILES_ARG.to_string(),
+        _ => key.replace('_', "-"),
+    }
+}
+
+/// The matches of the innermost subcommand, and how deeply it's nested.
+fn subcommand_matches<'a>(matches: &'a ArgMatches<'a>) -> (&'a ArgMatches<'a>, usize) {
+    match matches.subcommand() {
+        (_, Some(sub_matches)) => {
+            let (matches, depth) = subcommand_matches(sub_matches);
+            (matches, depth + 1)
+        }
+        _ => (matches, 0),
+    }
+}
+
+/// Insert the arguments of each option (keyed as in a config file) after the
+/// subcommand in `args`. Options which can be given more than once, e.g.
+/// `--mwaf` or the gpubox files, are replaced rather than added to when they
+/// are also on the command line. Parse the result with `app` and the
+/// `AllArgsOverrideSelf` setting, so the last value of other options wins.
+pub fn insert_args(
+    app: App,
+    args: Vec<OsString>,
+    option_args: Vec<(String, Vec<OsString>)>,
+) -> Vec<OsString> {
+    // Global options come before the subcommand and don't take values, so the
+    // subcommand is the first argument which isn't an option. Nested
+    // subcommands, e.g. `plot waterfall`, can't be told apart from positional
+    // arguments until the arguments are parsed, so first insert after the
+    // last of the arguments which follow it; clap takes options after
+    // positional arguments too.
+    let is_option = |arg: &OsString| arg.to_string_lossy().starts_with('-');
+    let first_index = match args.iter().skip(1).position(|arg| !is_option(arg)) {
+        Some(index) => index + 1,
+        None => return args,
+    };
+    let mut last_index = first_index;
+    while args.get(last_index + 1).is_some_and(|arg| !is_option(arg)) {
+        last_index += 1;
+    }
+    let merge = |subcommand_index: usize, option_args: &[(String, Vec<OsString>)]| {
+        let mut merged = args[..=subcommand_index].to_vec();
+        for (_, args) in option_args {
+            merged.extend(args.iter().cloned());
+        }
+        merged.extend(args[subcommand_index + 1..].iter().cloned());
+        merged
+    };
+
+    let merged = merge(last_index, &option_args);
+    let app = app.global_setting(AppSettings::AllArgsOverrideSelf);
+    let matches = match app.get_matches_from_safe(&merged) {
+        Ok(matches) => matches,
+        // Let the caller's parse report the error
+        Err(_) => return merged,
+    };
+    let (matches, depth) = subcommand_matches(&matches);
+    let count_values = |key: &str| {
+        matches
+            .values_of_os(arg_name(key))
+            .map_or(0, |values| values.count())
+    };
+    let overridden: Vec<(String, Vec<OsString>)> = option_args
+        .into_iter()
+        .filter(|(key, args)| count_values(key) <= args.len())
+        .collect();
+    merge(first_index + depth.saturating_sub(1), &overridden)
+}
+
+/// Insert options from the file given with `--config`, if any, with
+/// `insert_args`.
+pub fn args_with_config(app: App, args: Vec<OsString>) -> Result<Vec<OsString>, ScratchpadError> {
+    let path = match find_path_option(&args, "--config") {
+        Some(path) => path,
+        None => return Ok(args),
+    };
+    let options = read_config(&path)?;
+    let option_args = options
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), option_args(key, value)?)))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(|reason| ScratchpadError::Config {
+            path: path.display().to_string(),
+            reason,
+        })?;
+    Ok(insert_args(app, args, option_args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use structopt::StructOpt;
+
+    #[derive(StructOpt, Debug)]
+    struct TestOpt {
+        #[structopt(short, long)]
+        metafits: Option<String>,
+        #[structopt(name = "GPUBOX FILE")]
+        files: Vec<String>,
+        #[structopt(long)]
+        mwaf: Vec<String>,
+        #[structopt(short, long, default_value = "0")]
+        vis_radix: u8,
+        #[structopt(long)]
+        uvw: bool,
+        #[structopt(long)]
+        config: Option<String>,
+    }
+
+    #[derive(StructOpt, Debug)]
+    enum TestCommand {
+        Dump(TestOpt),
+        Plot(TestPlotCommand),
+    }
+
+    #[derive(StructOpt, Debug)]
+    enum TestPlotCommand {
+        Waterfall(TestOpt),
+    }
+
+    fn os_args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    fn parse(args: Vec<OsString>) -> TestOpt {
+        let matches = TestCommand::clap()
+            .global_setting(AppSettings::AllArgsOverrideSelf)
+            .get_matches_from(args);
+        match TestCommand::from_clap(&matches) {
+            TestCommand::Dump(opt) | TestCommand::Plot(TestPlotCommand::Waterfall(opt)) => opt,
+        }
+    }
+
+    fn config_args(config: Value) -> Vec<(String, Vec<OsString>)> {
+        config
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, value)| (key.clone(), option_args(key, value).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_path_option() {
+        let args = os_args(&["x", "dump", "--config", "a.toml"]);
+        assert_eq!(
+            find_path_option(&args, "--config"),
+            Some(PathBuf::from("a.toml"))
+        );
+        let args = os_args(&["x", "dump", "--config=b.yaml", "c.fits"]);
+        assert_eq!(
+            find_path_option(&args, "--config"),
+            Some(PathBuf::from("b.yaml"))
+        );
+        assert_eq!(find_path_option(&args, "--obs-dir"), None);
+    }
+
+    #[test]
+    fn test_option_args() {
+        assert_eq!(
+            option_args("vis_radix", &json!(16)).unwrap(),
+            os_args(&["--vis-radix=16"])
+        );
+        assert_eq!(
+            option_args("uvw", &json!(true)).unwrap(),
+            os_args(&["--uvw"])
+        );
+        assert!(option_args("uvw", &json!(false)).unwrap().is_empty());
+        assert_eq!(
+            option_args("mwaf", &json!(["a.mwaf", "b.mwaf"])).unwrap(),
+            os_args(&["--mwaf=a.mwaf", "--mwaf=b.mwaf"])
+        );
+        assert_eq!(
+            option_args(FILES_KEY, &json!(["a.fits", "b.fits"])).unwrap(),
+            os_args(&["a.fits", "b.fits"])
+        );
+        assert!(option_args("mwaf", &json!({ "a": 1 })).is_err());
+    }
+
+    #[test]
+    fn test_insert_args_after_subcommand() {
+        let args = insert_args(
+            TestCommand::clap(),
+            os_args(&["x", "plot", "waterfall", "c.fits"]),
+            config_args(json!({ "metafits": "m.metafits", "uvw": true })),
+        );
+        assert_eq!(
+            args,
+            os_args(&[
+                "x",
+                "plot",
+                "waterfall",
+                "--metafits=m.metafits",
+                "--uvw",
+                "c.fits"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_insert_args_from_config() {
+        let opt = parse(insert_args(
+            TestCommand::clap(),
+            os_args(&["x", "dump"]),
+            config_args(json!({
+                "metafits": "m.metafits",
+                "files": ["a.fits", "b.fits"],
+                "mwaf": ["a.mwaf"],
+                "vis_radix": 16,
+                "uvw": true,
+            })),
+        ));
+        assert_eq!(opt.metafits.as_deref(), Some("m.metafits"));
+        assert_eq!(opt.files, vec!["a.fits", "b.fits"]);
+        assert_eq!(opt.mwaf, vec!["a.mwaf"]);
+        assert_eq!(opt.vis_radix, 16);
+        assert!(opt.uvw);
+    }
+
+    #[test]
+    fn test_insert_args_command_line_overrides() {
+        let opt = parse(insert_args(
+            TestCommand::clap(),
+            os_args(&[
+                "x",
+                "dump",
+                "-v",
+                "8",
+                "--mwaf=b.mwaf",
+                "--mwaf=c.mwaf",
+                "c.fits",
+            ]),
+            config_args(json!({
+                "metafits": "m.metafits",
+                "files": ["a.fits", "b.fits"],
+                "mwaf": ["a.mwaf"],
+                "vis_radix": 16,
+            })),
+        ));
+        assert_eq!(opt.metafits.as_deref(), Some("m.metafits"));
+        assert_eq!(opt.files, vec!["c.fits"]);
+        assert_eq!(opt.mwaf, vec!["b.mwaf", "c.mwaf"]);
+        assert_eq!(opt.vis_radix, 8);
+    }
+
+    #[test]
+    fn test_args_with_config_yaml() {
+        let path = std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-config-{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "metafits: m.metafits\nvis-radix: 2\nuvw: false\n").unwrap();
+        let mut config_arg = OsString::from("--config=");
+        config_arg.push(&path);
+        let args = args_with_config(
+            TestCommand::clap(),
+            vec!["x".into(), "dump".into(), config_arg, "a.fits".into()],
+        );
+        std::fs::remove_file(&path).unwrap();
+        let opt = parse(args.unwrap());
+        assert_eq!(opt.metafits.as_deref(), Some("m.metafits"));
+        assert_eq!(opt.files, vec!["a.fits"]);
+        assert_eq!(opt.vis_radix, 2);
+        assert!(!opt.uvw);
+        assert!(opt.config.is_some());
+    }
+}
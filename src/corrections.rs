@@ -0,0 +1,678 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Optional corrections applied to visibilities from `read_by_baseline`,
+/// which are ordered [baseline][fine_chan][pol][re, im].
+use crate::pfb_gains;
+use crate::uvw::{baseline_xyzs, uvws_towards, PhaseCentre, Xyz, SPEED_OF_LIGHT_M_S};
+use crate::ScratchpadError;
+use mwalib::{Antenna, Baseline, CorrelatorVersion, MetafitsContext, RFInput};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The re and im of each of XX, XY, YX and YY.
+const FLOATS_PER_FINECHAN: usize = 8;
+
+/// Rotate the phase of the complex value in `re_im` by `angle_rad`.
+fn rotate(re_im: &mut [f32], angle_rad: f64) {
+    let (sin, cos) = angle_rad.sin_cos();
+    let (re, im) = (re_im[0] as f64, re_im[1] as f64);
+    re_im[0] = (re * cos - im * sin) as f32;
+    re_im[1] = (re * sin + im * cos) as f32;
+}
+
+/// The pair of rf inputs correlated in each visibility pol: XX, XY, YX, YY.
+fn pol_rf_inputs(antennas: &[Antenna], ant1: usize, ant2: usize) -> [(&RFInput, &RFInput); 4] {
+    let ant1 = &antennas[ant1];
+    let ant2 = &antennas[ant2];
+    [
+        (&ant1.rfinput_x, &ant2.rfinput_x),
+        (&ant1.rfinput_x, &ant2.rfinput_y),
+        (&ant1.rfinput_y, &ant2.rfinput_x),
+        (&ant1.rfinput_y, &ant2.rfinput_y),
+    ]
+}
+
+/// Undo the phase of a path difference of `path_diffs_m` for each pol of one
+/// baseline, at each fine channel frequency. This has the same sign as Birli.
+fn correct_path_diffs(
+    baseline_chunk: &mut [f32],
+    fine_chan_freqs_hz: &[f64],
+    path_diffs_m: &[f64; 4],
+) {
+    for (fine_chan_chunk, freq_hz) in baseline_chunk
+        .chunks_mut(FLOATS_PER_FINECHAN)
+        .zip(fine_chan_freqs_hz)
+    {
+        for (pol_chunk, path_diff_m) in fine_chan_chunk.chunks_mut(2).zip(path_diffs_m) {
+            rotate(
+                pol_chunk,
+                -2.0 * std::f64::consts::PI * path_diff_m * freq_hz / SPEED_OF_LIGHT_M_S,
+            );
+        }
+    }
+}
+
+/// Undo the phase introduced by the difference in electrical length between
+/// the two rf inputs of each baseline and pol, at each fine channel frequency.
+pub fn correct_cable_lengths(
+    context: &MetafitsContext,
+    img_buffer: &mut [f32],
+    fine_chan_freqs_hz: &[f64],
+) {
+    correct_antenna_cable_lengths(
+        &context.antennas,
+        &context.baselines,
+        img_buffer,
+        fine_chan_freqs_hz,
+    );
+}
+
+fn correct_antenna_cable_lengths(
+    antennas: &[Antenna],
+    baselines: &[Baseline],
+    img_buffer: &mut [f32],
+    fine_chan_freqs_hz: &[f64],
+) {
+    let floats_per_baseline = fine_chan_freqs_hz.len() * FLOATS_PER_FINECHAN;
+    for (baseline, baseline_chunk) in baselines
+        .iter()
+        .zip(img_buffer.chunks_mut(floats_per_baseline))
+    {
+        let length_diffs_m = pol_rf_inputs(antennas, baseline.ant1_index, baseline.ant2_index).map(
+            |(rf_input1, rf_input2)| rf_input2.electrical_length_m - rf_input1.electrical_length_m,
+        );
+        correct_path_diffs(baseline_chunk, fine_chan_freqs_hz, &length_diffs_m);
+    }
+}
+
+/// Re-phase visibilities from the zenith to `phase_centre`, by the difference
+/// in w towards each at `unix_time_ms`, at each fine channel frequency.
+pub fn rotate_phase_centre(
+    context: &MetafitsContext,
+    img_buffer: &mut [f32],
+    fine_chan_freqs_hz: &[f64],
+    phase_centre: PhaseCentre,
+    unix_time_ms: u64,
+) {
+    let w_diffs_m = w_diffs_m(&baseline_xyzs(context), phase_centre, unix_time_ms);
+    correct_w_diffs(img_buffer, fine_chan_freqs_hz, &w_diffs_m);
+}
+
+/// The w of each baseline separation towards `phase_centre`, less its w
+/// towards the zenith, at `unix_time_ms`.
+fn w_diffs_m(separations: &[Xyz], phase_centre: PhaseCentre, unix_time_ms: u64) -> Vec<f64> {
+    let uvws = uvws_towards(separations, phase_centre, unix_time_ms);
+    let zenith_uvws = uvws_towards(separations, PhaseCentre::zenith(unix_time_ms), unix_time_ms);
+    uvws.iter()
+        .zip(zenith_uvws.iter())
+        .map(|(uvw, zenith_uvw)| uvw.w - zenith_uvw.w)
+        .collect()
+}
+
+/// Undo the phase of the difference in w of each baseline, for every pol.
+fn correct_w_diffs(img_buffer: &mut [f32], fine_chan_freqs_hz: &[f64], w_diffs_m: &[f64]) {
+    let floats_per_baseline = fine_chan_freqs_hz.len() * FLOATS_PER_FINECHAN;
+    for (&w_diff_m, baseline_chunk) in w_diffs_m
+        .iter()
+        .zip(img_buffer.chunks_mut(floats_per_baseline))
+    {
+        correct_path_diffs(baseline_chunk, fine_chan_freqs_hz, &[w_diff_m; 4]);
+    }
+}
+
+/// Divide out the digital gain of both rf inputs of each baseline and pol, for
+/// receiver channel `rec_chan_number`. Digital gains in the metafits are in
+/// ascending order of `metafits_rec_chan_numbers`, and scaled by 64.
+pub fn correct_digital_gains(
+    context: &MetafitsContext,
+    img_buffer: &mut [f32],
+    metafits_rec_chan_numbers: &[usize],
+    rec_chan_number: usize,
+) -> Result<(), ScratchpadError> {
+    correct_antenna_digital_gains(
+        &context.antennas,
+        &context.baselines,
+        context.num_corr_fine_chans_per_coarse,
+        img_buffer,
+        metafits_rec_chan_numbers,
+        rec_chan_number,
+    )
+}
+
+fn correct_antenna_digital_gains(
+    antennas: &[Antenna],
+    baselines: &[Baseline],
+    num_fine_chans: usize,
+    img_buffer: &mut [f32],
+    metafits_rec_chan_numbers: &[usize],
+    rec_chan_number: usize,
+) -> Result<(), ScratchpadError> {
+    let gain_index = metafits_rec_chan_numbers
+        .iter()
+        .position(|&metafits_rec_chan_number| metafits_rec_chan_number == rec_chan_number)
+        .ok_or_else(|| ScratchpadError::DigitalGain {
+            rec_chan_number,
+            reason: "it isn't one of the metafits coarse channels".to_string(),
+        })?;
+    let digital_gain = |rf_input: &RFInput| match rf_input.digital_gains.get(gain_index) {
+        Some(&gain) => Ok(gain as f64 / 64.0),
+        None => Err(ScratchpadError::DigitalGain {
+            rec_chan_number,
+            reason: format!(
+                "rf input {}{} has only {} digital gains",
+                rf_input.tile_name,
+                rf_input.pol,
+                rf_input.digital_gains.len()
+            ),
+        }),
+    };
+    let floats_per_baseline = num_fine_chans * FLOATS_PER_FINECHAN;
+
+    for (baseline, baseline_chunk) in baselines
+        .iter()
+        .zip(img_buffer.chunks_mut(floats_per_baseline))
+    {
+        let scales = pol_rf_inputs(antennas, baseline.ant1_index, baseline.ant2_index)
+            .iter()
+            .map(|(rf_input1, rf_input2)| {
+                Ok((1.0 / (digital_gain(rf_input1)? * digital_gain(rf_input2)?)) as f32)
+            })
+            .collect::<Result<Vec<f32>, ScratchpadError>>()?;
+        for fine_chan_chunk in baseline_chunk.chunks_mut(FLOATS_PER_FINECHAN) {
+            for (pol_chunk, scale) in fine_chan_chunk.chunks_mut(2).zip(&scales) {
+                pol_chunk[0] *= scale;
+                pol_chunk[1] *= scale;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A PFB passband shape across one coarse channel.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Passband {
+    /// MWAX for MWAX observations, otherwise legacy 10 kHz.
+    Auto,
+    /// The legacy 10 kHz shape, averaged to 40 kHz.
+    Legacy40khz,
+    Legacy10khz,
+    Mwax,
+    /// One gain per whitespace separated value, at any resolution.
+    File(PathBuf),
+}
+
+impl FromStr for Passband {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Passband::Auto,
+            "legacy-40khz" => Passband::Legacy40khz,
+            "legacy-10khz" => Passband::Legacy10khz,
+            "mwax" => Passband::Mwax,
+            // Anything else should look like a path, rather than a mistyped name
+            _ if s.contains(std::path::is_separator) || Path::new(s).extension().is_some() => {
+                Passband::File(PathBuf::from(s))
+            }
+            _ => return Err(ScratchpadError::InvalidArgument(format!(
+                "unknown passband {}, expected legacy-40khz, legacy-10khz, mwax, auto or a file",
+                s
+            ))),
+        })
+    }
+}
+
+impl Passband {
+    /// The file the shape is read from, if it isn't built in.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Passband::File(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The gains of each of `num_fine_chans` fine channels, averaged from
+    /// the shape, which must have a multiple of `num_fine_chans` gains.
+    pub fn gains(
+        &self,
+        corr_version: CorrelatorVersion,
+        num_fine_chans: usize,
+    ) -> Result<Vec<f64>, ScratchpadError> {
+        match self {
+            Passband::Auto if corr_version == CorrelatorVersion::V2 => {
+                average_passband("mwax", pfb_gains::MWAX_200HZ, num_fine_chans)
+            }
+            Passband::Auto | Passband::Legacy10khz => {
+                average_passband("legacy-10khz", pfb_gains::LEGACY_10KHZ, num_fine_chans)
+            }
+            Passband::Legacy40khz => average_passband(
+                "legacy-40khz",
+                &average_passband("legacy-10khz", pfb_gains::LEGACY_10KHZ, 32)?,
+                num_fine_chans,
+            ),
+            Passband::Mwax => average_passband("mwax", pfb_gains::MWAX_200HZ, num_fine_chans),
+            Passband::File(path) => read_passband(path, num_fine_chans),
+        }
+    }
+}
+
+/// Average a passband shape down to `num_fine_chans` gains. Fine channel
+/// `n / 2` of both is centred on the coarse channel centre, so each fine
+/// channel averages the gains within half a fine channel of its centre, with
+/// half weight for gains on the edge, wrapping around the coarse channel.
+fn average_passband(
+    name: &str,
+    gains: &[f64],
+    num_fine_chans: usize,
+) -> Result<Vec<f64>, ScratchpadError> {
+    if gains.is_empty() || num_fine_chans == 0 || !gains.len().is_multiple_of(num_fine_chans) {
+        return Err(ScratchpadError::Passband {
+            path: name.to_string(),
+            num_gains: gains.len(),
+            num_fine_chans,
+        });
+    }
+    let gains_per_fine_chan = gains.len() / num_fine_chans;
+    let half_width = gains_per_fine_chan as isize / 2;
+    let gain = |index: isize| gains[index.rem_euclid(gains.len() as isize) as usize];
+    Ok((0..num_fine_chans)
+        .map(|fine_chan_index| {
+            let centre = (fine_chan_index * gains_per_fine_chan) as isize;
+            let sum: f64 = if gains_per_fine_chan % 2 == 1 {
+                (centre - half_width..=centre + half_width).map(gain).sum()
+            } else {
+                (centre - half_width + 1..centre + half_width)
+                    .map(gain)
+                    .sum::<f64>()
+                    + (gain(centre - half_width) + gain(centre + half_width)) / 2.0
+            };
+            sum / gains_per_fine_chan as f64
+        })
+        .collect())
+}
+
+/// Read a PFB passband shape covering one coarse channel, one gain per
+/// whitespace separated value, and average it down to `num_fine_chans`. This
+/// lets the same file (e.g. a 10 kHz legacy shape) correct any resolution it
+/// divides into (e.g. 40 kHz).
+pub fn read_passband<T: AsRef<Path>>(
+    path: &T,
+    num_fine_chans: usize,
+) -> Result<Vec<f64>, ScratchpadError> {
+    let gains = std::fs::read_to_string(path)?
+        .split_whitespace()
+        .map(|value| {
+            value.parse::<f64>().map_err(|_| {
+                ScratchpadError::InvalidArgument(format!(
+                    "passband {} has {}, which is not a number",
+                    path.as_ref().display(),
+                    value
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    average_passband(&path.as_ref().display().to_string(), &gains, num_fine_chans)
+}
+
+/// Divide each fine channel by its passband gain.
+pub fn correct_passband(context: &MetafitsContext, img_buffer: &mut [f32], passband: &[f64]) {
+    let floats_per_finechan = context.num_visibility_pols * 2;
+    let floats_per_baseline = passband.len() * floats_per_finechan;
+
+    for baseline_chunk in img_buffer.chunks_mut(floats_per_baseline) {
+        for (fine_chan_chunk, gain) in baseline_chunk.chunks_mut(floats_per_finechan).zip(passband)
+        {
+            let scale = (1.0 / gain) as f32;
+            for float_val in fine_chan_chunk {
+                *float_val *= scale;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uvw::Enu;
+    use mwalib::{Pol, MWA_LATITUDE_RADIANS};
+    use num_complex::Complex;
+
+    fn test_rf_input(pol: Pol, electrical_length_m: f64, digital_gains: Vec<u32>) -> RFInput {
+        RFInput {
+            input: 0,
+            ant: 0,
+            tile_id: 11,
+            tile_name: "Tile011".to_string(),
+            pol,
+            electrical_length_m,
+            north_m: 0.,
+            east_m: 0.,
+            height_m: 0.,
+            vcs_order: 0,
+            subfile_order: 0,
+            flagged: false,
+            digital_gains,
+            dipole_gains: vec![1.; 16],
+            dipole_delays: vec![0; 16],
+            rec_number: 1,
+            rec_slot_number: 1,
+        }
+    }
+
+    /// An antenna with X and Y electrical lengths and digital gains.
+    fn test_antenna(lengths_m: [f64; 2], digital_gains: [Vec<u32>; 2]) -> Antenna {
+        let [x_gains, y_gains] = digital_gains;
+        Antenna {
+            ant: 0,
+            tile_id: 11,
+            tile_name: "Tile011".to_string(),
+            rfinput_x: test_rf_input(Pol::X, lengths_m[0], x_gains),
+            rfinput_y: test_rf_input(Pol::Y, lengths_m[1], y_gains),
+        }
+    }
+
+    fn test_baselines() -> Vec<Baseline> {
+        vec![
+            Baseline {
+                ant1_index: 0,
+                ant2_index: 0,
+            },
+            Baseline {
+                ant1_index: 0,
+                ant2_index: 1,
+            },
+        ]
+    }
+
+    /// The complex value of each pol of each fine channel of each baseline.
+    fn complexes(img_buffer: &[f32]) -> Vec<Complex<f32>> {
+        img_buffer
+            .chunks(2)
+            .map(|re_im| Complex::new(re_im[0], re_im[1]))
+            .collect()
+    }
+
+    fn assert_complex_close(actual: Complex<f32>, expected: Complex<f32>) {
+        assert!(
+            (actual - expected).norm() < 1e-5,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_correct_cable_lengths() {
+        let antennas = vec![
+            test_antenna([100., 100.], [vec![], vec![]]),
+            test_antenna([101.5, 102.], [vec![], vec![]]),
+        ];
+        let freq_hz = 150e6;
+        // 1 + 0i for every pol of the auto and the cross
+        let mut img_buffer: Vec<f32> = [1., 0.].repeat(2 * 4);
+        correct_antenna_cable_lengths(&antennas, &test_baselines(), &mut img_buffer, &[freq_hz]);
+        let vis = complexes(&img_buffer);
+
+        // The auto's rf inputs have the same lengths, so it's unchanged
+        for &auto_vis in &vis[..4] {
+            assert_eq!(auto_vis, Complex::new(1., 0.));
+        }
+        // The cross is rotated back by the phase of the length difference of each pol
+        let wavelength_m = SPEED_OF_LIGHT_M_S / freq_hz;
+        for (&cross_vis, length_diff_m) in vis[4..].iter().zip(&[1.5, 2., 1.5, 2.]) {
+            let angle_rad = -2.0 * std::f64::consts::PI * length_diff_m / wavelength_m;
+            assert_complex_close(cross_vis, Complex::from_polar(1., angle_rad as f32));
+        }
+        // -2π 1.5 m / 1.998 m
+        assert_complex_close(vis[4], Complex::from_polar(1., -4.715_651_3));
+    }
+
+    fn assert_gains_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-12,
+                "{:?} is not close to {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotate_zenith_to_zenith() {
+        let separations: Vec<Xyz> = [(10., 0., 0.), (0., -20., 3.), (120., 45., -2.)]
+            .iter()
+            .map(|&(e, n, u)| Enu { e, n, u }.to_xyz(MWA_LATITUDE_RADIANS))
+            .collect();
+        let unix_time_ms = 1_381_844_910_000;
+        let w_diffs_m = w_diffs_m(
+            &separations,
+            PhaseCentre::zenith(unix_time_ms),
+            unix_time_ms,
+        );
+        for w_diff_m in w_diffs_m.iter() {
+            assert!(w_diff_m.abs() < 1e-9, "{} is not 0", w_diff_m);
+        }
+
+        let fine_chan_freqs_hz = [139.0e6, 139.04e6];
+        let img_buffer: Vec<f32> = (0..separations.len() * fine_chan_freqs_hz.len() * 8)
+            .map(|index| index as f32 - 20.)
+            .collect();
+        let mut rotated = img_buffer.clone();
+        correct_w_diffs(&mut rotated, &fine_chan_freqs_hz, &w_diffs_m);
+        for (actual, expected) in complexes(&rotated).iter().zip(complexes(&img_buffer)) {
+            assert_complex_close(*actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_rotate_phase_centre_by_w_diff() {
+        // A vertical baseline has a w of 10 m towards the zenith, and 5 m
+        // towards 60 degrees further north on the meridian
+        let separations = [Enu {
+            e: 0.,
+            n: 0.,
+            u: 10.,
+        }
+        .to_xyz(MWA_LATITUDE_RADIANS)];
+        let unix_time_ms = 1_381_844_910_000;
+        let zenith = PhaseCentre::zenith(unix_time_ms);
+        let phase_centre = PhaseCentre {
+            ra_rad: zenith.ra_rad,
+            dec_rad: zenith.dec_rad + 60_f64.to_radians(),
+        };
+        let w_diffs_m = w_diffs_m(&separations, phase_centre, unix_time_ms);
+        assert!((w_diffs_m[0] + 5.).abs() < 1e-9, "{:?}", w_diffs_m);
+
+        let freq_hz = 150e6;
+        let mut img_buffer: Vec<f32> = [1., 0.].repeat(4);
+        correct_w_diffs(&mut img_buffer, &[freq_hz], &w_diffs_m);
+        let angle_rad = 2.0 * std::f64::consts::PI * 5. * freq_hz / SPEED_OF_LIGHT_M_S;
+        for vis in complexes(&img_buffer) {
+            assert_complex_close(vis, Complex::from_polar(1., angle_rad as f32));
+        }
+    }
+
+    #[test]
+    fn test_average_passband_same_resolution() {
+        let gains = [0.5, 0.9, 1.0, 0.9];
+        assert_gains_close(&average_passband("test", &gains, 4).unwrap(), &gains);
+    }
+
+    #[test]
+    fn test_average_passband_odd_ratio() {
+        // Each fine channel averages the gain at its centre and one either side
+        let gains = [0.5, 0.8, 0.9, 1.0, 0.9, 0.8];
+        assert_gains_close(
+            &average_passband("test", &gains, 2).unwrap(),
+            &[(0.8 + 0.5 + 0.8) / 3., (0.9 + 1.0 + 0.9) / 3.],
+        );
+    }
+
+    #[test]
+    fn test_average_passband_even_ratio() {
+        // Gains half a fine channel from the centre are shared with the neighbours
+        let gains = [0.5, 0.7, 0.9, 1.0, 1.0, 1.0, 0.9, 0.7];
+        assert_gains_close(
+            &average_passband("test", &gains, 2).unwrap(),
+            &[
+                (0.9 / 2. + 0.7 + 0.5 + 0.7 + 0.9 / 2.) / 4.,
+                (0.9 / 2. + 1.0 + 1.0 + 1.0 + 0.9 / 2.) / 4.,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_average_passband_not_a_multiple() {
+        assert!(matches!(
+            average_passband("test", &[1.0; 10], 4),
+            Err(ScratchpadError::Passband {
+                num_gains: 10,
+                num_fine_chans: 4,
+                ..
+            })
+        ));
+        assert!(average_passband("test", &[], 4).is_err());
+    }
+
+    #[test]
+    fn test_built_in_passbands_are_centred() {
+        // The centre fine channel of each shape is at the top of the passband
+        for (passband, num_fine_chans) in &[
+            (Passband::Legacy10khz, 128),
+            (Passband::Legacy40khz, 32),
+            (Passband::Mwax, 128),
+            (Passband::Mwax, 32),
+        ] {
+            let gains = passband
+                .gains(CorrelatorVersion::Legacy, *num_fine_chans)
+                .unwrap();
+            assert_eq!(gains.len(), *num_fine_chans);
+            assert!((gains[num_fine_chans / 2] - 1.0).abs() < 0.01);
+            assert!(gains[0] < 0.9);
+        }
+    }
+
+    #[test]
+    fn test_auto_passband_from_corr_version() {
+        assert_eq!(
+            Passband::Auto.gains(CorrelatorVersion::V2, 128).unwrap(),
+            Passband::Mwax.gains(CorrelatorVersion::V2, 128).unwrap()
+        );
+        assert_eq!(
+            Passband::Auto
+                .gains(CorrelatorVersion::Legacy, 128)
+                .unwrap(),
+            Passband::Legacy10khz
+                .gains(CorrelatorVersion::Legacy, 128)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_passband_from_str() {
+        assert_eq!("mwax".parse::<Passband>().unwrap(), Passband::Mwax);
+        assert_eq!(
+            "legacy-40khz".parse::<Passband>().unwrap(),
+            Passband::Legacy40khz
+        );
+        assert_eq!(
+            "pfb.txt".parse::<Passband>().unwrap(),
+            Passband::File(PathBuf::from("pfb.txt"))
+        );
+        assert_eq!(
+            "passbands/pfb".parse::<Passband>().unwrap(),
+            Passband::File(PathBuf::from("passbands/pfb"))
+        );
+        assert!(matches!(
+            "legacy_10khz".parse::<Passband>(),
+            Err(ScratchpadError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_correct_digital_gains() {
+        // Gains for receiver channels 109, 110 and 111, scaled by 64
+        let antennas = vec![
+            test_antenna([0., 0.], [vec![64, 128, 64], vec![64, 64, 64]]),
+            test_antenna([0., 0.], [vec![64, 256, 64], vec![64, 32, 64]]),
+        ];
+        let num_fine_chans = 2;
+        let mut img_buffer: Vec<f32> = [8., -8.].repeat(2 * num_fine_chans * 4);
+        correct_antenna_digital_gains(
+            &antennas,
+            &test_baselines(),
+            num_fine_chans,
+            &mut img_buffer,
+            &[109, 110, 111],
+            110,
+        )
+        .unwrap();
+        let vis = complexes(&img_buffer);
+
+        // Antenna 0 has gains 2 (X) and 1 (Y), antenna 1 has 4 (X) and 0.5 (Y)
+        let auto_scales = [1. / 4., 1. / 2., 1. / 2., 1.];
+        let cross_scales = [1. / 8., 1., 1. / 4., 2.];
+        for fine_chan in 0..num_fine_chans {
+            for pol in 0..4 {
+                let auto_vis = vis[fine_chan * 4 + pol];
+                let cross_vis = vis[(num_fine_chans + fine_chan) * 4 + pol];
+                assert_eq!(auto_vis, Complex::new(8., -8.) * auto_scales[pol]);
+                assert_eq!(cross_vis, Complex::new(8., -8.) * cross_scales[pol]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_correct_digital_gains_missing() {
+        let antennas = vec![
+            test_antenna([0., 0.], [vec![64, 64], vec![64, 64]]),
+            test_antenna([0., 0.], [vec![64, 64], vec![64]]),
+        ];
+        let mut img_buffer = vec![1.; 2 * 8];
+        let mut correct = |rec_chan_number| {
+            correct_antenna_digital_gains(
+                &antennas,
+                &test_baselines(),
+                1,
+                &mut img_buffer,
+                &[109, 110],
+                rec_chan_number,
+            )
+        };
+        assert!(correct(109).is_ok());
+        assert!(matches!(
+            correct(111),
+            Err(ScratchpadError::DigitalGain {
+                rec_chan_number: 111,
+                ..
+            })
+        ));
+        // Antenna 1's Y rf input has no gain for the second channel
+        assert!(matches!(
+            correct(110),
+            Err(ScratchpadError::DigitalGain {
+                rec_chan_number: 110,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_read_passband_averages() {
+        let path = std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-passband-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "0.5\n0.8 0.9\n1.0\n0.9\n0.8\n").unwrap();
+        let gains = read_passband(&path, 2);
+        std::fs::remove_file(&path).unwrap();
+        assert_gains_close(
+            &gains.unwrap(),
+            &[(0.8 + 0.5 + 0.8) / 3., (0.9 + 1.0 + 0.9) / 3.],
+        );
+    }
+}
@@ -3,11 +3,21 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 /// Given gpubox files, provide a way to output/dump visibilities.
-use anyhow::Error;
-use mwalib::{get_antennas_from_baseline, CorrelatorContext};
-use radix_fmt::radix;
-use std::fs::File;
-use std::io::Write;
+use crate::corrections::Passband;
+use crate::mwaf::read_mwafs_for_context;
+use crate::output::Compression;
+use crate::provenance::Provenance;
+use crate::sink::{create_sink, DumpFormat, DumpMetadata, RecordBlock};
+use crate::uvw::{baseline_uvws, PhaseCentre};
+use crate::vis::{VisCorrections, VisFlagging, VisPlan, VisRecord, VisSelection};
+use crate::ScratchpadError;
+use indicatif::{ProgressBar, ProgressStyle};
+use log::{info, log_enabled, Level};
+use mwalib::CorrelatorContext;
+use num_complex::Complex;
+use rayon::prelude::*;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 
 #[cfg(not(tarpaulin_include))]
@@ -22,107 +32,311 @@ pub struct DumpAllDataOpt {
     #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
     pub files: Vec<std::path::PathBuf>,
 
-    // Dump filename
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Load options from a TOML or YAML file, which options given here override
+    #[structopt(long, parse(from_os_str))]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Dump filename, or - (or leave it out) to write to stdout
     #[structopt(short, long, parse(from_os_str))]
-    pub dump_filename: std::path::PathBuf,
+    pub dump_filename: Option<std::path::PathBuf>,
+
+    /// Format to dump in, instead of guessing from the dump filename's .json, .fits or .parquet
+    /// extension
+    #[structopt(long, possible_values = &["csv", "json", "fits", "parquet"])]
+    pub format: Option<DumpFormat>,
 
-    // Radix (base) of visibility values
+    /// Radix (base) of visibility values, for csv
     #[structopt(short, long, default_value = "0")]
     pub vis_radix: u8,
 
-    // Dump absolute of float values
+    /// Dump absolute of float values
     #[structopt(short, long)]
     pub absolute: bool,
+
+    /// Add the UVW of each baseline towards the metafits phase centre, in metres and wavelengths
+    #[structopt(long)]
+    pub uvw: bool,
+
+    /// Correct the phase of each baseline and pol for the difference in rf input electrical lengths
+    #[structopt(long)]
+    pub cable_delay: bool,
+
+    /// Re-phase visibilities from the zenith to the metafits phase centre
+    #[structopt(long)]
+    pub rephase: bool,
+
+    /// Right ascension in degrees to re-phase to instead of the metafits phase centre, with --rephase
+    #[structopt(long, requires_all = &["phase-dec", "rephase"], allow_hyphen_values = true)]
+    pub phase_ra: Option<f64>,
+
+    /// Declination in degrees to re-phase to instead of the metafits phase centre, with --rephase
+    #[structopt(long, requires_all = &["phase-ra", "rephase"], allow_hyphen_values = true)]
+    pub phase_dec: Option<f64>,
+
+    /// Divide out the digital gains of each rf input for each coarse channel
+    #[structopt(long)]
+    pub digital_gains: bool,
+
+    /// Divide out a PFB passband shape: legacy-40khz, legacy-10khz, mwax, auto (mwax or
+    /// legacy-10khz from the correlator version), or a file of gains across a coarse channel at any
+    /// resolution which is a multiple of the observation's fine channels
+    #[structopt(long)]
+    pub passband: Option<Passband>,
+
+    /// Flag baselines with a tile flagged in the metafits
+    #[structopt(long)]
+    pub flag_tiles: bool,
+
+    /// Flag timesteps before the metafits good time (the end of the quack time)
+    #[structopt(long)]
+    pub flag_quack: bool,
+
+    /// Apply flags from mwaf files, e.g. from cotter or Birli, matched to coarse channels by gpubox
+    /// number
+    #[structopt(long, parse(from_os_str))]
+    pub mwaf: Vec<std::path::PathBuf>,
+
+    /// Only dump these coarse channel indices (the coarse_chan column), comma separated
+    #[structopt(long, use_delimiter = true)]
+    pub coarse_chans: Vec<usize>,
+
+    /// Only dump these timestep indices, comma separated
+    #[structopt(long, use_delimiter = true)]
+    pub timesteps: Vec<usize>,
+
+    /// Only dump these baseline indices, comma separated
+    #[structopt(long, use_delimiter = true)]
+    pub baselines: Vec<usize>,
+
+    /// Only dump these fine channel indices within each coarse channel, comma separated
+    #[structopt(long, use_delimiter = true)]
+    pub fine_chans: Vec<usize>,
+
+    /// Drop flagged rows instead of marking them in the flagged column
+    #[structopt(long)]
+    pub drop_flagged: bool,
+
+    /// Read and format timesteps on all cores (set RAYON_NUM_THREADS to limit), keeping the same
+    /// row order
+    #[structopt(long)]
+    pub parallel: bool,
+
+    /// Compress with none, gzip or zstd, instead of guessing from the dump filename's .gz or .zst
+    /// extension
+    #[structopt(long, possible_values = &["none", "gzip", "zstd"])]
+    pub compression: Option<Compression>,
+
+    /// Record only the path and size of each input in the provenance, without reading it again for
+    /// its SHA-256
+    #[structopt(long)]
+    pub no_checksums: bool,
+}
+
+/// Everything needed to dump any timestep of any coarse channel, computed
+/// once up front so it can be shared between threads.
+struct DumpPlan<'a> {
+    opts: &'a DumpAllDataOpt,
+    vis: VisPlan,
+    phase_centre: PhaseCentre,
+    flagging: bool,
+}
+
+impl<'a> DumpPlan<'a> {
+    fn new(opts: &'a DumpAllDataOpt, context: &CorrelatorContext) -> Result<Self, ScratchpadError> {
+        let passband = match &opts.passband {
+            Some(passband) => Some(passband.gains(
+                context.corr_version,
+                context.metafits_context.num_corr_fine_chans_per_coarse,
+            )?),
+            None => None,
+        };
+        let phase_centre = match (opts.phase_ra, opts.phase_dec) {
+            (Some(ra_deg), Some(dec_deg)) => PhaseCentre::from_degrees(ra_deg, dec_deg),
+            _ => PhaseCentre::from_metafits(&context.metafits_context),
+        };
+        let selection = VisSelection {
+            coarse_chans: opts.coarse_chans.clone(),
+            timesteps: opts.timesteps.clone(),
+            baselines: opts.baselines.clone(),
+            fine_chans: opts.fine_chans.clone(),
+            drop_flagged: opts.drop_flagged,
+        };
+        let corrections = VisCorrections {
+            digital_gains: opts.digital_gains,
+            passband,
+            cable_delay: opts.cable_delay,
+            phase_centre: if opts.rephase {
+                Some(phase_centre)
+            } else {
+                None
+            },
+        };
+        let flagging = VisFlagging {
+            flag_tiles: opts.flag_tiles,
+            flag_quack: opts.flag_quack,
+            mwafs: read_mwafs_for_context(context, &opts.mwaf)?,
+        };
+
+        Ok(DumpPlan {
+            opts,
+            vis: VisPlan::new(context, selection, corrections, flagging)?,
+            phase_centre,
+            flagging: opts.flag_tiles || opts.flag_quack || !opts.mwaf.is_empty(),
+        })
+    }
+
+    fn metadata(&self, context: &CorrelatorContext, provenance: Provenance) -> DumpMetadata {
+        DumpMetadata {
+            obs_id: context.metafits_context.obs_id,
+            corr_version: context.corr_version.to_string(),
+            num_coarse_chans: context.num_coarse_chans,
+            num_timesteps: context.num_timesteps,
+            num_baselines: context.metafits_context.num_baselines,
+            num_fine_chans_per_coarse: context.metafits_context.num_corr_fine_chans_per_coarse,
+            fine_chan_width_hz: context.metafits_context.corr_fine_chan_width_hz,
+            int_time_ms: context.metafits_context.corr_int_time_ms,
+            uvw: self.opts.uvw,
+            flagged: self.flagging,
+            provenance,
+        }
+    }
+
+    /// Read and correct every selected record of one timestep of one coarse
+    /// channel, returning them with the sum and count of their floats.
+    fn read_block(
+        &self,
+        context: &mut CorrelatorContext,
+        coarse_channel_index: usize,
+        timestep_index: usize,
+    ) -> Result<(RecordBlock, f64, u64), ScratchpadError> {
+        let mut sum: f64 = 0.;
+        let mut float_count: u64 = 0;
+
+        let timestep_vis = self
+            .vis
+            .read_timestep(context, coarse_channel_index, timestep_index)?;
+        let uvws = if self.opts.uvw {
+            baseline_uvws(
+                &context.metafits_context,
+                self.phase_centre,
+                timestep_vis.centroid_unix_time_ms,
+            )
+        } else {
+            vec![]
+        };
+        let mut records: Vec<VisRecord> = timestep_vis.records().collect();
+        for record in records.iter_mut() {
+            for pol in record.vis.iter_mut() {
+                sum += pol.re as f64 + pol.im as f64;
+                float_count += 2;
+                if self.opts.absolute {
+                    *pol = Complex::new(pol.re.abs(), pol.im.abs());
+                }
+            }
+        }
+
+        Ok((RecordBlock { records, uvws }, sum, float_count))
+    }
 }
 
 #[cfg(not(tarpaulin_include))]
-pub fn dump_all_data<T: AsRef<std::path::Path>>(
-    metafits: &T,
-    files: &[T],
-    dump_filename: &T,
-    vis_radix: u8,
-    absolute: bool,
-) -> Result<(), Error> {
-    let mut dump_file = File::create(dump_filename)?;
-    println!("Dumping data via mwalib...");
-    let mut context = CorrelatorContext::new(metafits, files)?;
-    let coarse_channel_array = context.coarse_chans.clone();
-    let timestep_array = context.timesteps.clone();
-
-    println!("Correlator version: {}", context.corr_version);
-
-    let floats_per_finechan = context.metafits_context.num_visibility_pols * 2;
-    let floats_per_baseline =
-        context.metafits_context.num_corr_fine_chans_per_coarse * floats_per_finechan;
+pub fn dump_all_data(
+    opts: &DumpAllDataOpt,
+    command_line: &[OsString],
+) -> Result<(), ScratchpadError> {
+    info!("Dumping data via mwalib...");
+    let mut context = CorrelatorContext::new(&opts.metafits, &opts.files)?;
+
+    info!("Correlator version: {}", context.corr_version);
+
+    let plan = DumpPlan::new(opts, &context)?;
+    let format = opts.format.unwrap_or_else(|| {
+        opts.dump_filename
+            .as_ref()
+            .map_or(DumpFormat::Csv, DumpFormat::from_path)
+    });
+    let mut sink = create_sink(
+        format,
+        opts.dump_filename.as_deref(),
+        opts.compression,
+        opts.vis_radix,
+    )?;
+    let inputs: Vec<&Path> = std::iter::once(&opts.metafits)
+        .chain(opts.files.iter())
+        .map(PathBuf::as_path)
+        .chain(opts.passband.as_ref().and_then(Passband::path))
+        .chain(opts.mwaf.iter().map(PathBuf::as_path))
+        .chain(opts.config.as_deref())
+        .collect();
+    let provenance = Provenance::new(
+        command_line,
+        &inputs,
+        context.corr_version,
+        !opts.no_checksums,
+    )?;
+    sink.begin(&plan.metadata(&context, provenance))?;
+
+    // Rows are ordered by coarse channel, then timestep
+    let jobs = plan.vis.jobs();
+    let progress = if log_enabled!(Level::Info) {
+        ProgressBar::new(jobs.len() as u64)
+    } else {
+        ProgressBar::hidden()
+    };
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template(
+                "{elapsed_precise} [{wide_bar}] {pos}/{len} timesteps ({per_sec}, ETA {eta})",
+            )?
+            .progress_chars("=> "),
+    );
 
     let mut sum: f64 = 0.;
     let mut float_count: u64 = 0;
-    writeln!(
-        &mut dump_file,
-        "coarse_chan,timestep,baseline,ant1_name,ant2_name,fine_chan,xx_re,xx_im,xy_re,xy_im,yx_re,yx_im,yy_re,yy_im"
-    )?;
-    for (coarse_channel_index, coarse_channel) in coarse_channel_array.iter().enumerate() {
-        for (timestep_index, timestep) in timestep_array.iter().enumerate() {
-            println!(
-                "Reading coarse chan: {} ({}) {:.3} Mhz, timestep {} ({:?})",
-                coarse_channel_index,
-                coarse_channel.rec_chan_number,
-                coarse_channel.chan_centre_hz as f32 / 1.0e6,
-                timestep_index,
-                timestep
-            );
-            let img_buffer = context.read_by_baseline(timestep_index, coarse_channel_index)?;
-
-            for (baseline_index, baseline_chunk) in
-                img_buffer.chunks(floats_per_baseline).enumerate()
-            {
-                let (ant1, ant2) = get_antennas_from_baseline(
-                    baseline_index,
-                    context.metafits_context.num_ants,
-                )
-                .unwrap();
-                let ant1_name: String = context.metafits_context.antennas[ant1]
-                    .tile_name
-                    .to_string();
-                let ant2_name: String = context.metafits_context.antennas[ant2]
-                    .tile_name
-                    .to_string();
-
-                for (fine_chan_index, fine_chan_chunk) in
-                    baseline_chunk.chunks(floats_per_finechan).enumerate()
-                {
-                    write!(
-                        &mut dump_file,
-                        "{},{},{},{},{},{}",
-                        coarse_channel_index,
-                        timestep_index,
-                        baseline_index,
-                        ant1_name,
-                        ant2_name,
-                        fine_chan_index,
-                    )?;
-                    for &float_val in fine_chan_chunk {
-                        let abs_val = if absolute { float_val.abs() } else { float_val };
-                        if vis_radix > 0 {
-                            let radix_val = radix(
-                                abs_val as u64,
-                                vis_radix,
-                            );
-                            write!(&mut dump_file, ",{}", radix_val)?;
-                        } else {
-                            write!(&mut dump_file, ",{}", abs_val)?;
-                        }
-                        println!("{:08}", abs_val);
-                        sum += float_val as f64;
-                        float_count += 1;
-                    }
-                    writeln!(&mut dump_file)?;
-                }
+    if opts.parallel {
+        // mwalib needs a mutable context to read, so each thread gets its own.
+        // Jobs run a chunk at a time, one per thread, and each chunk is written
+        // in order before the next starts.
+        let num_threads = rayon::current_num_threads().min(jobs.len()).max(1);
+        info!("Dumping with {} threads", num_threads);
+        let mut contexts = vec![context];
+        for _ in 1..num_threads {
+            contexts.push(CorrelatorContext::new(&opts.metafits, &opts.files)?);
+        }
+        for chunk in jobs.chunks(num_threads) {
+            let blocks = chunk
+                .par_iter()
+                .zip(contexts.par_iter_mut())
+                .map(|(&(coarse_channel_index, timestep_index), context)| {
+                    plan.read_block(context, coarse_channel_index, timestep_index)
+                })
+                .collect::<Result<Vec<_>, ScratchpadError>>()?;
+            for (block, block_sum, block_count) in blocks {
+                sink.write_block(&block)?;
+                sum += block_sum;
+                float_count += block_count;
             }
+            progress.inc(chunk.len() as u64);
+        }
+    } else {
+        for &(coarse_channel_index, timestep_index) in jobs.iter() {
+            let (block, block_sum, block_count) =
+                plan.read_block(&mut context, coarse_channel_index, timestep_index)?;
+            sink.write_block(&block)?;
+            sum += block_sum;
+            float_count += block_count;
+            progress.inc(1);
         }
     }
 
-    println!("Sum was {}, count was {} floats", sum, float_count);
+    sink.finish()?;
+    progress.finish();
+    info!("Sum was {}, count was {} floats", sum, float_count);
 
     Ok(())
 }
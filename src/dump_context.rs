@@ -4,6 +4,10 @@
 
 /// Given an observation's data, verify that `mwalib` is functioning correctly
 /// by printing an observation context.
+use crate::gpubox::TimeMapCoverage;
+use crate::serialize::serialize_context;
+use crate::ScratchpadError;
+use mwalib::CorrelatorContext;
 use structopt::StructOpt;
 
 #[cfg(not(tarpaulin_include))]
@@ -17,16 +21,29 @@ pub struct DumpContextOpt {
     /// Paths to the observation's gpubox files.
     #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
     pub files: Vec<std::path::PathBuf>,
-}
 
-// #[cfg(not(tarpaulin_include))]
-// fn main() -> Result<(), anyhow::Error> {
-//     let opts = DumpContextOpt::from_args();
-//     let mut context = mwalibContext::new(&opts.metafits, &opts.files)?;
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
 
-//     context.rf_inputs.sort_by_key(|k| k.subfile_order);
+    /// Load options from a TOML or YAML file, which options given here override
+    #[structopt(long, parse(from_os_str))]
+    pub config: Option<std::path::PathBuf>,
+}
 
-//     println!("{}", context);
+#[cfg(not(tarpaulin_include))]
+pub fn dump_context<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    files: &[T],
+) -> Result<(), ScratchpadError> {
+    let context = CorrelatorContext::new(metafits, files)?;
+    let gpubox_time_map = TimeMapCoverage::new(&context, files)?;
 
-//     Ok(())
-// }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serialize_context(context, gpubox_time_map))?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Errors returned by the mwa-scratchpad library.
+use mwalib::MwalibError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScratchpadError {
+    /// An error from mwalib, including reading gpubox files.
+    #[error(transparent)]
+    Mwalib(#[from] MwalibError),
+
+    /// An error reading or writing a FITS file, e.g. an mwaf file.
+    #[error(transparent)]
+    Fitsio(#[from] fitsio::errors::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// An error writing a Parquet file.
+    #[error(transparent)]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    ProgressTemplate(#[from] indicatif::style::TemplateError),
+
+    /// A command line choice that isn't one of the possible values.
+    #[error("unknown {kind} {value}")]
+    UnknownChoice { kind: &'static str, value: String },
+
+    #[error("unrecognised gpubox filename {0}")]
+    GpuboxFilename(String),
+
+    #[error(
+        "passband {path} has {num_gains} gains, which is not a multiple of {num_fine_chans} fine channels"
+    )]
+    Passband {
+        path: String,
+        num_gains: usize,
+        num_fine_chans: usize,
+    },
+
+    /// A coarse channel with no digital gains in the metafits.
+    #[error("no digital gain for receiver channel {rec_chan_number}: {reason}")]
+    DigitalGain {
+        rec_chan_number: usize,
+        reason: String,
+    },
+
+    /// An mwaf file which doesn't match the observation, or can't be read.
+    #[error("{path} {reason}")]
+    Mwaf { path: String, reason: String },
+
+    /// A config file which can't be parsed, or has an option that can't be
+    /// given on the command line.
+    #[error("config file {path}: {reason}")]
+    Config { path: String, reason: String },
+
+    #[error("no coarse channels to plot")]
+    NoCoarseChannels,
+
+    #[error("{0} is not a valid font")]
+    InvalidFont(String),
+
+    /// An error from the plotting backend.
+    #[error("plotting failed: {0}")]
+    Plot(String),
+
+    #[error("{0}")]
+    InvalidArgument(String),
+}
+
+impl ScratchpadError {
+    /// Whether this is from writing to a closed pipe, e.g. when piping a dump
+    /// into `head`, which isn't worth reporting.
+    pub fn is_broken_pipe(&self) -> bool {
+        match self {
+            ScratchpadError::Io(err) => err.kind() == std::io::ErrorKind::BrokenPipe,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_broken_pipe() {
+        let broken_pipe = std::io::Error::from(std::io::ErrorKind::BrokenPipe);
+        assert!(ScratchpadError::from(broken_pipe).is_broken_pipe());
+        let not_found = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert!(!ScratchpadError::from(not_found).is_broken_pipe());
+        assert!(!ScratchpadError::NoCoarseChannels.is_broken_pipe());
+    }
+}
@@ -0,0 +1,532 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given gpubox files, triage RFI with a SumThreshold detector (Offringa et
+/// al. 2010) over time × frequency for each baseline and pol.
+use crate::mwaf::{write_mwaf, MwafFile};
+use crate::output::{Compression, DumpWriter};
+use crate::provenance::Provenance;
+use crate::ScratchpadError;
+use log::{debug, info};
+use mwalib::{CorrelatorContext, MwalibError};
+use std::ffi::OsString;
+use std::io::Write;
+use structopt::StructOpt;
+
+/// Flags for one coarse channel, ordered [timestep][baseline][fine_chan].
+#[derive(Debug, Clone)]
+pub struct FlagMask {
+    pub num_timesteps: usize,
+    pub num_baselines: usize,
+    pub num_fine_chans: usize,
+    pub flags: Vec<bool>,
+}
+
+impl FlagMask {
+    pub fn new(num_timesteps: usize, num_baselines: usize, num_fine_chans: usize) -> Self {
+        FlagMask {
+            num_timesteps,
+            num_baselines,
+            num_fine_chans,
+            flags: vec![false; num_timesteps * num_baselines * num_fine_chans],
+        }
+    }
+
+    pub fn index(&self, timestep: usize, baseline: usize, fine_chan: usize) -> usize {
+        (timestep * self.num_baselines + baseline) * self.num_fine_chans + fine_chan
+    }
+
+    /// The flags of every fine channel for a timestep and baseline.
+    pub fn fine_chans(&self, timestep: usize, baseline: usize) -> &[bool] {
+        let start = self.index(timestep, baseline, 0);
+        &self.flags[start..start + self.num_fine_chans]
+    }
+
+    /// The fraction of flagged samples in a timestep.
+    pub fn timestep_occupancy(&self, timestep: usize) -> f64 {
+        let chunk_size = self.num_baselines * self.num_fine_chans;
+        let chunk = &self.flags[timestep * chunk_size..(timestep + 1) * chunk_size];
+        chunk.iter().filter(|&&flag| flag).count() as f64 / chunk_size as f64
+    }
+
+    /// The fraction of flagged samples overall.
+    pub fn occupancy(&self) -> f64 {
+        self.flags.iter().filter(|&&flag| flag).count() as f64 / self.flags.len() as f64
+    }
+}
+
+/// The threshold drop per doubling of the window suggested by Offringa et al.
+pub const SUM_THRESHOLD_RHO: f32 = 1.5;
+
+/// Parameters of the SumThreshold detector.
+#[derive(Debug, Clone, Copy)]
+pub struct SumThresholdParams {
+    /// Threshold for a single sample, in robust standard deviations.
+    pub threshold: f32,
+    /// The largest window, in samples. Windows double from 1 up to this.
+    pub max_window: usize,
+    /// How much the threshold drops each time the window doubles.
+    pub rho: f32,
+}
+
+/// One SumThreshold pass along a 1D series: flag every window of `window`
+/// samples whose mean exceeds `threshold`, counting previously flagged
+/// samples as exactly `threshold`.
+fn sum_threshold_1d(values: &[f32], flags: &mut [bool], window: usize, threshold: f32) {
+    if window > values.len() {
+        return;
+    }
+    let sample = |i: usize, flags: &[bool]| if flags[i] { threshold } else { values[i] };
+    let mut new_flags = vec![false; values.len()];
+    let mut sum: f32 = (0..window).map(|i| sample(i, flags)).sum();
+    for start in 0..=values.len() - window {
+        if start > 0 {
+            sum += sample(start + window - 1, flags) - sample(start - 1, flags);
+        }
+        if sum > threshold * window as f32 {
+            for new_flag in &mut new_flags[start..start + window] {
+                *new_flag = true;
+            }
+        }
+    }
+    for (flag, new_flag) in flags.iter_mut().zip(new_flags) {
+        *flag |= new_flag;
+    }
+}
+
+/// Normalise amplitudes to robust standard deviations from the median.
+fn normalise(amplitudes: &mut [f32]) {
+    if amplitudes.is_empty() {
+        return;
+    }
+    let mut sorted = amplitudes.to_vec();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median = sorted[sorted.len() / 2];
+    let mut deviations: Vec<f32> = sorted.iter().map(|a| (a - median).abs()).collect();
+    deviations.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let sigma = 1.4826 * deviations[deviations.len() / 2];
+    for amplitude in amplitudes.iter_mut() {
+        *amplitude = if sigma > 0. {
+            (*amplitude - median) / sigma
+        } else {
+            0.
+        };
+    }
+}
+
+/// Run SumThreshold over a [timestep][fine_chan] grid of normalised values,
+/// along frequency then time for each window size.
+fn sum_threshold_2d(
+    values: &[f32],
+    flags: &mut [bool],
+    num_timesteps: usize,
+    num_fine_chans: usize,
+    params: SumThresholdParams,
+) {
+    let mut window = 1;
+    let mut threshold = params.threshold;
+    while window <= params.max_window {
+        for timestep_index in 0..num_timesteps {
+            let range = timestep_index * num_fine_chans..(timestep_index + 1) * num_fine_chans;
+            sum_threshold_1d(&values[range.clone()], &mut flags[range], window, threshold);
+        }
+        for fine_chan_index in 0..num_fine_chans {
+            let column: Vec<f32> = (0..num_timesteps)
+                .map(|timestep_index| values[timestep_index * num_fine_chans + fine_chan_index])
+                .collect();
+            let mut column_flags: Vec<bool> = (0..num_timesteps)
+                .map(|timestep_index| flags[timestep_index * num_fine_chans + fine_chan_index])
+                .collect();
+            sum_threshold_1d(&column, &mut column_flags, window, threshold);
+            for (timestep_index, flag) in column_flags.into_iter().enumerate() {
+                flags[timestep_index * num_fine_chans + fine_chan_index] = flag;
+            }
+        }
+        window *= 2;
+        threshold /= params.rho;
+    }
+}
+
+/// The most amplitudes to hold at once, 1 GiB of them.
+const MAX_AMPLITUDES: usize = 1 << 28;
+
+/// How many baselines of `baseline_size` amplitudes each to flag at once, so
+/// that at most `MAX_AMPLITUDES` are held.
+fn baselines_per_batch(num_baselines: usize, baseline_size: usize) -> usize {
+    (MAX_AMPLITUDES / baseline_size.max(1)).clamp(1, num_baselines.max(1))
+}
+
+/// Flag one baseline's [pol][timestep][fine_chan] amplitudes, which are
+/// normalised in place, returning [timestep][fine_chan] flags. A sample is
+/// flagged if any of its pols is.
+fn flag_baseline(
+    amplitudes: &mut [f32],
+    num_timesteps: usize,
+    num_fine_chans: usize,
+    params: SumThresholdParams,
+) -> Vec<bool> {
+    let grid_size = num_timesteps * num_fine_chans;
+    let mut flags = vec![false; grid_size];
+    for pol_amplitudes in amplitudes.chunks_mut(grid_size) {
+        normalise(pol_amplitudes);
+        let mut pol_flags = vec![false; grid_size];
+        sum_threshold_2d(
+            pol_amplitudes,
+            &mut pol_flags,
+            num_timesteps,
+            num_fine_chans,
+            params,
+        );
+        for (flag, pol_flag) in flags.iter_mut().zip(pol_flags) {
+            *flag |= pol_flag;
+        }
+    }
+    flags
+}
+
+/// Flag every baseline of a coarse channel, reading all of its timesteps.
+/// Baselines are flagged a batch at a time, reading every timestep again for
+/// each batch, so long observations fit in memory.
+pub fn flag_coarse_chan(
+    context: &mut CorrelatorContext,
+    coarse_chan_index: usize,
+    params: SumThresholdParams,
+) -> Result<FlagMask, ScratchpadError> {
+    let num_timesteps = context.num_timesteps;
+    let num_baselines = context.metafits_context.num_baselines;
+    let num_fine_chans = context.metafits_context.num_corr_fine_chans_per_coarse;
+    let num_pols = context.metafits_context.num_visibility_pols;
+
+    let grid_size = num_timesteps * num_fine_chans;
+    let baseline_size = num_pols * grid_size;
+    let batch_size = baselines_per_batch(num_baselines, baseline_size);
+    if batch_size < num_baselines {
+        debug!(
+            "Flagging {} baselines at a time, reading each timestep {} times",
+            batch_size,
+            num_baselines.div_ceil(batch_size)
+        );
+    }
+    let floats_per_baseline = num_fine_chans * num_pols * 2;
+
+    let mut mask = FlagMask::new(num_timesteps, num_baselines, num_fine_chans);
+    for batch_start in (0..num_baselines).step_by(batch_size) {
+        let batch_end = (batch_start + batch_size).min(num_baselines);
+        // amplitudes ordered [baseline][pol][timestep][fine_chan]
+        let mut amplitudes = vec![0_f32; (batch_end - batch_start) * baseline_size];
+        for timestep_index in 0..num_timesteps {
+            let img_buffer = context
+                .read_by_baseline(timestep_index, coarse_chan_index)
+                .map_err(MwalibError::from)?;
+            let batch_buffer =
+                &img_buffer[batch_start * floats_per_baseline..batch_end * floats_per_baseline];
+            for (sample_index, re_im) in batch_buffer.chunks(2).enumerate() {
+                let pol = sample_index % num_pols;
+                let fine_chan = (sample_index / num_pols) % num_fine_chans;
+                let baseline = sample_index / (num_pols * num_fine_chans);
+                amplitudes[(baseline * num_pols + pol) * grid_size
+                    + timestep_index * num_fine_chans
+                    + fine_chan] = (re_im[0] * re_im[0] + re_im[1] * re_im[1]).sqrt();
+            }
+        }
+
+        for (batch_index, baseline_amplitudes) in amplitudes.chunks_mut(baseline_size).enumerate() {
+            let flags = flag_baseline(baseline_amplitudes, num_timesteps, num_fine_chans, params);
+            for (grid_index, flag) in flags.into_iter().enumerate() {
+                let flag_index = mask.index(
+                    grid_index / num_fine_chans,
+                    batch_start + batch_index,
+                    grid_index % num_fine_chans,
+                );
+                mask.flags[flag_index] = flag;
+            }
+        }
+    }
+    Ok(mask)
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-flag", author)]
+pub struct FlagOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Threshold for a single sample, in robust standard deviations from the median amplitude
+    #[structopt(short, long, default_value = "6")]
+    pub threshold: f32,
+
+    /// Largest SumThreshold window, in samples
+    #[structopt(long, default_value = "32")]
+    pub max_window: usize,
+
+    /// Write each baseline's flag mask to this CSV, one row per coarse channel, timestep and baseline
+    #[structopt(short, long, parse(from_os_str))]
+    pub dump_filename: Option<std::path::PathBuf>,
+
+    /// Write each coarse channel's flags to an mwaf file named by this template, where %% is replaced
+    /// by the gpubox number, e.g. flags_%%.mwaf
+    #[structopt(long)]
+    pub mwaf: Option<String>,
+
+    /// Compress with none, gzip or zstd, instead of guessing from the dump filename's .gz or .zst
+    /// extension
+    #[structopt(long, possible_values = &["none", "gzip", "zstd"])]
+    pub compression: Option<Compression>,
+
+    /// Record only the path and size of each input in the dump's provenance, without reading it
+    /// again for its SHA-256
+    #[structopt(long)]
+    pub no_checksums: bool,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn flag(opts: &FlagOpt, command_line: &[OsString]) -> Result<(), ScratchpadError> {
+    let params = SumThresholdParams {
+        threshold: opts.threshold,
+        max_window: opts.max_window,
+        rho: SUM_THRESHOLD_RHO,
+    };
+    let mut context = CorrelatorContext::new(&opts.metafits, &opts.files)?;
+    let mut dump_file = match &opts.dump_filename {
+        Some(dump_filename) => {
+            let mut dump_file = DumpWriter::create(dump_filename, opts.compression)?;
+            let inputs: Vec<&std::path::Path> = std::iter::once(&opts.metafits)
+                .chain(opts.files.iter())
+                .map(std::path::PathBuf::as_path)
+                .collect();
+            Provenance::new(
+                command_line,
+                &inputs,
+                context.corr_version,
+                !opts.no_checksums,
+            )?
+            .write_comments(&mut dump_file)?;
+            writeln!(
+                &mut dump_file,
+                "coarse_chan,timestep,baseline,ant1_name,ant2_name,fine_chan_flags"
+            )?;
+            Some(dump_file)
+        }
+        None => None,
+    };
+
+    let mut masks = Vec::with_capacity(context.num_coarse_chans);
+    for coarse_chan_index in 0..context.num_coarse_chans {
+        info!(
+            "Flagging coarse chan: {} ({})",
+            coarse_chan_index, context.coarse_chans[coarse_chan_index].rec_chan_number
+        );
+        let mask = flag_coarse_chan(&mut context, coarse_chan_index, params)?;
+
+        if let Some(dump_file) = &mut dump_file {
+            for timestep_index in 0..mask.num_timesteps {
+                for (baseline_index, baseline) in
+                    context.metafits_context.baselines.iter().enumerate()
+                {
+                    let fine_chan_flags: String = mask
+                        .fine_chans(timestep_index, baseline_index)
+                        .iter()
+                        .map(|&flag| if flag { '1' } else { '0' })
+                        .collect();
+                    writeln!(
+                        dump_file,
+                        "{},{},{},{},{},{}",
+                        coarse_chan_index,
+                        timestep_index,
+                        baseline_index,
+                        context.metafits_context.antennas[baseline.ant1_index].tile_name,
+                        context.metafits_context.antennas[baseline.ant2_index].tile_name,
+                        fine_chan_flags
+                    )?;
+                }
+            }
+        }
+        if let Some(mwaf_template) = &opts.mwaf {
+            let gpubox_number = context.coarse_chans[coarse_chan_index].gpubox_number;
+            let mwaf_filename = mwaf_template.replace("%%", &format!("{:02}", gpubox_number));
+            info!("Writing {}", mwaf_filename);
+            write_mwaf(
+                &mwaf_filename,
+                &MwafFile {
+                    gps_time_ms: context.timesteps[0].gps_time_ms,
+                    gpubox_number,
+                    num_ants: context.metafits_context.num_ants,
+                    mask: mask.clone(),
+                },
+            )?;
+        }
+        masks.push(mask);
+    }
+    if let Some(dump_file) = dump_file {
+        dump_file.finish()?;
+    }
+
+    info!("Occupancy by coarse channel:");
+    for (coarse_chan, mask) in context.coarse_chans.iter().zip(masks.iter()) {
+        info!(
+            "{:>4} {:>8.3} MHz {:>7.2}%",
+            coarse_chan.rec_chan_number,
+            coarse_chan.chan_centre_hz as f64 / 1e6,
+            mask.occupancy() * 100.
+        );
+    }
+
+    info!("Occupancy by timestep:");
+    for (timestep_index, timestep) in context.timesteps.iter().enumerate() {
+        let occupancy = masks
+            .iter()
+            .map(|mask| mask.timestep_occupancy(timestep_index))
+            .sum::<f64>()
+            / masks.len() as f64;
+        info!(
+            "{:>4} {:>14} {:>7.2}%",
+            timestep_index,
+            timestep.unix_time_ms,
+            occupancy * 100.
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: SumThresholdParams = SumThresholdParams {
+        threshold: 6.,
+        max_window: 32,
+        rho: SUM_THRESHOLD_RHO,
+    };
+
+    /// A [timestep][fine_chan] grid of low-level noise, normalised already.
+    fn noise_grid(num_timesteps: usize, num_fine_chans: usize) -> Vec<f32> {
+        (0..num_timesteps * num_fine_chans)
+            .map(|index| if (index * 7) % 3 == 0 { 0.5 } else { -0.5 })
+            .collect()
+    }
+
+    #[test]
+    fn test_sum_threshold_1d_spike() {
+        let mut values = vec![0_f32; 16];
+        values[5] = 10.;
+        let mut flags = vec![false; 16];
+        sum_threshold_1d(&values, &mut flags, 1, 6.);
+        let flagged: Vec<usize> = (0..16).filter(|&i| flags[i]).collect();
+        assert_eq!(flagged, vec![5]);
+    }
+
+    #[test]
+    fn test_sum_threshold_1d_window_longer_than_values() {
+        let values = vec![100_f32; 4];
+        let mut flags = vec![false; 4];
+        sum_threshold_1d(&values, &mut flags, 8, 1.);
+        assert!(flags.iter().all(|&flag| !flag));
+    }
+
+    #[test]
+    fn test_sum_threshold_2d_spike() {
+        let (num_timesteps, num_fine_chans) = (16, 32);
+        let mut values = noise_grid(num_timesteps, num_fine_chans);
+        values[4 * num_fine_chans + 10] = 20.;
+        let mut flags = vec![false; values.len()];
+        sum_threshold_2d(&values, &mut flags, num_timesteps, num_fine_chans, PARAMS);
+        let flagged: Vec<usize> = (0..flags.len()).filter(|&i| flags[i]).collect();
+        assert_eq!(flagged, vec![4 * num_fine_chans + 10]);
+    }
+
+    #[test]
+    fn test_sum_threshold_2d_broadband_line() {
+        // Too faint to flag any one sample, but not across the band
+        let (num_timesteps, num_fine_chans) = (16, 32);
+        let mut values = noise_grid(num_timesteps, num_fine_chans);
+        for value in &mut values[8 * num_fine_chans..9 * num_fine_chans] {
+            *value = 3.;
+        }
+        let mut flags = vec![false; values.len()];
+        sum_threshold_2d(&values, &mut flags, num_timesteps, num_fine_chans, PARAMS);
+        for timestep_index in 0..num_timesteps {
+            let row =
+                &flags[timestep_index * num_fine_chans..(timestep_index + 1) * num_fine_chans];
+            if timestep_index == 8 {
+                assert!(row.iter().all(|&flag| flag));
+            } else {
+                assert!(row.iter().all(|&flag| !flag), "timestep {}", timestep_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_threshold_2d_noise() {
+        let (num_timesteps, num_fine_chans) = (16, 32);
+        let values = noise_grid(num_timesteps, num_fine_chans);
+        let mut flags = vec![false; values.len()];
+        sum_threshold_2d(&values, &mut flags, num_timesteps, num_fine_chans, PARAMS);
+        assert!(flags.iter().all(|&flag| !flag));
+    }
+
+    #[test]
+    fn test_normalise_median_mad() {
+        let mut amplitudes = vec![4., 1., 100., 3., 2.];
+        normalise(&mut amplitudes);
+        // The median is 3 and the median absolute deviation is 1
+        let sigma = 1.4826;
+        let expected = [1. / sigma, -2. / sigma, 97. / sigma, 0., -1. / sigma];
+        for (amplitude, expected) in amplitudes.iter().zip(expected.iter()) {
+            assert!((amplitude - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_normalise_constant() {
+        let mut amplitudes = vec![5.; 8];
+        normalise(&mut amplitudes);
+        assert!(amplitudes.iter().all(|&amplitude| amplitude == 0.));
+    }
+
+    #[test]
+    fn test_normalise_empty() {
+        let mut amplitudes: Vec<f32> = vec![];
+        normalise(&mut amplitudes);
+        assert!(amplitudes.is_empty());
+    }
+
+    #[test]
+    fn test_baselines_per_batch() {
+        assert_eq!(baselines_per_batch(8256, 4 * 56 * 32), 8256);
+        assert_eq!(baselines_per_batch(8256, MAX_AMPLITUDES / 100), 100);
+        // At least one baseline, however big
+        assert_eq!(baselines_per_batch(8256, MAX_AMPLITUDES * 2), 1);
+        assert_eq!(baselines_per_batch(0, 0), 1);
+    }
+
+    #[test]
+    fn test_flag_baseline_any_pol() {
+        // A spike in a different sample of each of two pols
+        let (num_timesteps, num_fine_chans, num_pols) = (16, 32, 4);
+        let grid_size = num_timesteps * num_fine_chans;
+        let mut amplitudes: Vec<f32> = (0..num_pols * grid_size)
+            .map(|index| 10. + ((index * 7) % 5) as f32 * 0.1)
+            .collect();
+        amplitudes[4 * num_fine_chans + 10] = 100.;
+        amplitudes[3 * grid_size + 9 * num_fine_chans + 20] = 100.;
+        let flags = flag_baseline(&mut amplitudes, num_timesteps, num_fine_chans, PARAMS);
+        let flagged: Vec<usize> = (0..grid_size).filter(|&i| flags[i]).collect();
+        assert_eq!(
+            flagged,
+            vec![4 * num_fine_chans + 10, 9 * num_fine_chans + 20]
+        );
+    }
+}
@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Inspect gpubox files directly, for the details `mwalib` keeps to itself.
+use crate::ScratchpadError;
+use fitsio::FitsFile;
+use mwalib::{CorrelatorContext, CorrelatorVersion};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// A gpubox file, identified by the channel and batch in its filename.
+#[derive(Serialize, Debug, Clone)]
+pub struct GpuboxFile {
+    pub filename: String,
+    /// Legacy: gpubox host number 01..24, MWAX: receiver channel number 001..255
+    pub channel_identifier: usize,
+    pub batch_number: usize,
+}
+
+impl GpuboxFile {
+    /// Parse the channel identifier and batch number out of a legacy
+    /// (`_gpuboxNN_NN.fits`, `_gpuboxNN.fits`) or MWAX (`_chNNN_NNN.fits`) filename.
+    pub fn from_path<T: AsRef<Path>>(path: &T) -> Result<Self, ScratchpadError> {
+        let re = Regex::new(
+            r"_(ch(?P<channel>\d{3})_(?P<mwax_batch>\d{3})|gpubox(?P<band>\d{2})(_(?P<batch>\d{2}))?)\.fits$",
+        )
+        .expect("gpubox filename regex is valid");
+        let filename = path.as_ref().to_string_lossy().to_string();
+        let caps = re
+            .captures(&filename)
+            .ok_or_else(|| ScratchpadError::GpuboxFilename(filename.clone()))?;
+        let invalid = || ScratchpadError::GpuboxFilename(filename.clone());
+        let channel = caps
+            .name("channel")
+            .or_else(|| caps.name("band"))
+            .ok_or_else(invalid)?;
+        let batch = caps.name("mwax_batch").or_else(|| caps.name("batch"));
+        Ok(GpuboxFile {
+            channel_identifier: channel.as_str().parse().map_err(|_| invalid())?,
+            batch_number: match batch {
+                Some(batch) => batch.as_str().parse().map_err(|_| invalid())?,
+                None => 0,
+            },
+            filename,
+        })
+    }
+}
+
+/// The same layout as `CorrelatorContext::gpubox_time_map`, which is private
+/// to `mwalib`: unix time (ms) -> gpubox channel identifier -> (batch, hdu index)
+pub type GpuboxTimeMap = BTreeMap<u64, BTreeMap<usize, (usize, usize)>>;
+
+/// Read the `TIME` and `MILLITIM` of every visibility HDU in each gpubox file.
+pub fn create_time_map(
+    gpubox_files: &[GpuboxFile],
+    corr_version: CorrelatorVersion,
+) -> Result<GpuboxTimeMap, ScratchpadError> {
+    // MWAX files interleave visibility and weight HDUs
+    let step_size = if corr_version == CorrelatorVersion::V2 {
+        2
+    } else {
+        1
+    };
+    let mut time_map = GpuboxTimeMap::new();
+    for gpubox_file in gpubox_files {
+        let mut fptr = FitsFile::open(&gpubox_file.filename)?;
+        let num_hdus = fptr.iter().count();
+        for hdu_index in (1..num_hdus).step_by(step_size) {
+            let hdu = fptr.hdu(hdu_index)?;
+            let time: i64 = hdu.read_key(&mut fptr, "TIME")?;
+            let millitime: i64 = hdu.read_key(&mut fptr, "MILLITIM")?;
+            time_map
+                .entry((time * 1000 + millitime) as u64)
+                .or_default()
+                .entry(gpubox_file.channel_identifier)
+                .or_insert((gpubox_file.batch_number, hdu_index));
+        }
+    }
+    Ok(time_map)
+}
+
+/// A row of the coverage grid, one per correlator integration.
+#[derive(Serialize, Debug)]
+pub struct TimeMapRow {
+    pub unix_time_ms: u64,
+    /// Index into the context's timesteps, if mwalib knows about this time.
+    pub timestep: Option<usize>,
+    /// (batch, hdu index) for each coarse channel, `None` where there is a gap.
+    pub hdus: Vec<Option<(usize, usize)>>,
+}
+
+/// A timestamp for which a coarse channel has no HDU.
+#[derive(Serialize, Debug)]
+pub struct TimeMapGap {
+    pub unix_time_ms: u64,
+    pub gpubox_number: usize,
+}
+
+/// The gpubox time map as a grid of timestep × coarse channel.
+#[derive(Serialize, Debug)]
+pub struct TimeMapCoverage {
+    /// The gpubox number of each coarse channel expected from the metafits.
+    pub gpubox_numbers: Vec<usize>,
+    pub rows: Vec<TimeMapRow>,
+    pub gaps: Vec<TimeMapGap>,
+}
+
+impl TimeMapCoverage {
+    pub fn new<T: AsRef<Path>>(
+        context: &CorrelatorContext,
+        files: &[T],
+    ) -> Result<Self, ScratchpadError> {
+        let gpubox_files = files
+            .iter()
+            .map(GpuboxFile::from_path)
+            .collect::<Result<Vec<_>, _>>()?;
+        let time_map = create_time_map(&gpubox_files, context.corr_version)?;
+        let gpubox_numbers: Vec<usize> = context
+            .metafits_context
+            .get_expected_coarse_channels(context.corr_version)?
+            .iter()
+            .map(|coarse_chan| coarse_chan.gpubox_number)
+            .collect();
+        let timestep_unix_times_ms: Vec<u64> = context
+            .timesteps
+            .iter()
+            .map(|timestep| timestep.unix_time_ms)
+            .collect();
+        Ok(Self::from_time_map(
+            &time_map,
+            gpubox_numbers,
+            context.metafits_context.corr_int_time_ms,
+            &timestep_unix_times_ms,
+        ))
+    }
+
+    /// The coverage of `gpubox_numbers` by a time map, given the integration
+    /// time and the unix times (ms) of mwalib's timesteps.
+    pub fn from_time_map(
+        time_map: &GpuboxTimeMap,
+        gpubox_numbers: Vec<usize>,
+        int_time_ms: u64,
+        timestep_unix_times_ms: &[u64],
+    ) -> Self {
+        // Include integrations which are missing from every gpubox file.
+        let mut times: BTreeSet<u64> = time_map.keys().cloned().collect();
+        if let (Some(&first), Some(&last)) = (times.iter().next(), times.iter().next_back()) {
+            if int_time_ms > 0 {
+                times.extend((first..=last).step_by(int_time_ms as usize));
+            }
+        }
+
+        let mut rows = Vec::with_capacity(times.len());
+        let mut gaps = Vec::new();
+        for unix_time_ms in times {
+            let hdus: Vec<Option<(usize, usize)>> = gpubox_numbers
+                .iter()
+                .map(|gpubox_number| {
+                    time_map
+                        .get(&unix_time_ms)
+                        .and_then(|submap| submap.get(gpubox_number))
+                        .cloned()
+                })
+                .collect();
+            for (&gpubox_number, hdu) in gpubox_numbers.iter().zip(hdus.iter()) {
+                if hdu.is_none() {
+                    gaps.push(TimeMapGap {
+                        unix_time_ms,
+                        gpubox_number,
+                    });
+                }
+            }
+            rows.push(TimeMapRow {
+                unix_time_ms,
+                timestep: timestep_unix_times_ms
+                    .iter()
+                    .position(|&timestep_unix_time_ms| timestep_unix_time_ms == unix_time_ms),
+                hdus,
+            });
+        }
+
+        TimeMapCoverage {
+            gpubox_numbers,
+            rows,
+            gaps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpubox_file_from_legacy_path() {
+        let gpubox_file =
+            GpuboxFile::from_path(&"data/1101503312_20141201210818_gpubox01_00.fits").unwrap();
+        assert_eq!(gpubox_file.channel_identifier, 1);
+        assert_eq!(gpubox_file.batch_number, 0);
+
+        let gpubox_file =
+            GpuboxFile::from_path(&"1065880128_20131015134930_gpubox24_02.fits").unwrap();
+        assert_eq!(gpubox_file.channel_identifier, 24);
+        assert_eq!(gpubox_file.batch_number, 2);
+        assert_eq!(
+            gpubox_file.filename,
+            "1065880128_20131015134930_gpubox24_02.fits"
+        );
+    }
+
+    #[test]
+    fn test_gpubox_file_from_old_legacy_path() {
+        let gpubox_file =
+            GpuboxFile::from_path(&"1059505936_20130802190000_gpubox13.fits").unwrap();
+        assert_eq!(gpubox_file.channel_identifier, 13);
+        assert_eq!(gpubox_file.batch_number, 0);
+    }
+
+    #[test]
+    fn test_gpubox_file_from_mwax_path() {
+        let gpubox_file =
+            GpuboxFile::from_path(&"1244973688_20190619100110_ch114_001.fits").unwrap();
+        assert_eq!(gpubox_file.channel_identifier, 114);
+        assert_eq!(gpubox_file.batch_number, 1);
+    }
+
+    #[test]
+    fn test_gpubox_file_from_bad_path() {
+        assert!(matches!(
+            GpuboxFile::from_path(&"1244973688.metafits"),
+            Err(ScratchpadError::GpuboxFilename(_))
+        ));
+        assert!(GpuboxFile::from_path(&"1244973688_20190619100110_ch114_001.fits.gz").is_err());
+    }
+
+    #[test]
+    fn test_time_map_coverage_gaps() {
+        // Gpubox 02 is missing its second integration, and every gpubox its third
+        let mut time_map = GpuboxTimeMap::new();
+        for (unix_time_ms, gpubox_numbers) in [
+            (1_000_000, vec![1, 2]),
+            (1_002_000, vec![1]),
+            (1_006_000, vec![1, 2]),
+        ] {
+            for (hdu_index, gpubox_number) in gpubox_numbers.into_iter().enumerate() {
+                time_map
+                    .entry(unix_time_ms)
+                    .or_default()
+                    .insert(gpubox_number, (0, hdu_index + 1));
+            }
+        }
+        let coverage = TimeMapCoverage::from_time_map(
+            &time_map,
+            vec![1, 2],
+            2000,
+            &[1_000_000, 1_002_000, 1_004_000, 1_006_000],
+        );
+
+        let times: Vec<u64> = coverage.rows.iter().map(|row| row.unix_time_ms).collect();
+        assert_eq!(times, [1_000_000, 1_002_000, 1_004_000, 1_006_000]);
+        let timesteps: Vec<Option<usize>> = coverage.rows.iter().map(|row| row.timestep).collect();
+        assert_eq!(timesteps, [Some(0), Some(1), Some(2), Some(3)]);
+        assert_eq!(coverage.rows[1].hdus, [Some((0, 1)), None]);
+        assert_eq!(coverage.rows[2].hdus, [None, None]);
+        let gaps: Vec<(u64, usize)> = coverage
+            .gaps
+            .iter()
+            .map(|gap| (gap.unix_time_ms, gap.gpubox_number))
+            .collect();
+        assert_eq!(gaps, [(1_002_000, 2), (1_004_000, 1), (1_004_000, 2)]);
+    }
+}
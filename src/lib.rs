@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Scratchpad tools for the MWA: dumping, correcting, flagging, plotting and
+//! serializing visibilities and metadata read through mwalib. The `mwa-scratchpad`
+//! binary is a thin command line interface over these modules.
+
+pub mod baselines;
+pub mod channels;
+pub mod config;
+pub mod corrections;
+pub mod dump_all_data;
+pub mod dump_context;
+pub mod error;
+pub mod flag;
+pub mod gpubox;
+pub mod metafits_info;
+pub mod mwaf;
+pub mod obs_dir;
+pub mod output;
+pub mod peek;
+pub mod pfb_gains;
+pub mod plot;
+pub mod provenance;
+pub mod rf_inputs;
+pub mod serialize;
+pub mod sink;
+pub mod time_map;
+pub mod uvw;
+pub mod vis;
+
+pub use error::ScratchpadError;
@@ -4,27 +4,162 @@
 
 /// Given gpubox files, provide a way to output/dump visibilities.
 use anyhow::Error;
+use structopt::clap::AppSettings;
 use structopt::StructOpt;
 
-mod dump_all_data;
-use dump_all_data::DumpAllDataOpt;
+use mwa_scratchpad::baselines::{self, BaselinesOpt};
+use mwa_scratchpad::channels::{self, ChannelsOpt};
+use mwa_scratchpad::config;
+use mwa_scratchpad::dump_all_data::{self, DumpAllDataOpt};
+use mwa_scratchpad::dump_context::{self, DumpContextOpt};
+use mwa_scratchpad::flag::{self, FlagOpt};
+use mwa_scratchpad::metafits_info::{self, MetafitsInfoOpt};
+use mwa_scratchpad::obs_dir;
+use mwa_scratchpad::peek::{self, PeekOpt};
+use mwa_scratchpad::plot::{self, PlotOpt};
+use mwa_scratchpad::rf_inputs::{self, RFInputsOpt};
+use mwa_scratchpad::time_map::{self, TimeMapOpt};
 
 #[derive(StructOpt, Debug)]
 enum Args {
+    Baselines(BaselinesOpt),
+    Channels(ChannelsOpt),
     DumpAllData(DumpAllDataOpt),
+    DumpContext(DumpContextOpt),
+    Flag(FlagOpt),
+    MetafitsInfo(MetafitsInfoOpt),
+    Peek(PeekOpt),
+    Plot(PlotOpt),
+    RfInputs(RFInputsOpt),
+    TimeMap(TimeMapOpt),
+}
+
+#[derive(StructOpt, Debug)]
+struct Opts {
+    /// Log more detail, -v for debug and -vv for trace. RUST_LOG overrides this
+    #[structopt(short, long, parse(from_occurrences))]
+    verbose: u8,
+
+    /// Only log warnings and errors, and hide progress bars
+    #[structopt(short, long)]
+    quiet: bool,
+
+    #[structopt(subcommand)]
+    args: Args,
+}
+
+/// The log level for `-v` given `verbose` times, or `-q`.
+fn log_level(verbose: u8, quiet: bool) -> &'static str {
+    match (quiet, verbose) {
+        (true, _) => "warn",
+        (false, 0) => "info",
+        (false, 1) => "debug",
+        _ => "trace",
+    }
+}
+
+fn setup_logging(verbose: u8, quiet: bool) {
+    env_logger::Builder::from_env(
+        env_logger::Env::default().default_filter_or(log_level(verbose, quiet)),
+    )
+    .init();
 }
 
 fn main() -> Result<(), Error> {
-    match Args::from_args() {
-        Args::DumpAllData(DumpAllDataOpt {
+    // Options from a config file are inserted before those on the command line,
+    // which override them
+    let args = config::args_with_config(Opts::clap(), std::env::args_os().collect())?;
+    let (args, obs_files) = obs_dir::args_with_obs_dir(Opts::clap(), args)?;
+    // Dumps record the command line with the config file's options and the observation's files
+    // filled in
+    let command_line = args.clone();
+    let matches = Opts::clap()
+        .global_setting(AppSettings::AllArgsOverrideSelf)
+        .get_matches_from(args);
+    let Opts {
+        verbose,
+        quiet,
+        args,
+    } = Opts::from_clap(&matches);
+    setup_logging(verbose, quiet);
+    if let Some(obs_files) = obs_files {
+        obs_files.warn_missing();
+    }
+    match args {
+        Args::Baselines(BaselinesOpt { metafits, json }) => {
+            baselines::dump_baselines(&metafits, json)?;
+            Ok(())
+        }
+        Args::Channels(ChannelsOpt {
             metafits,
             files,
-            dump_filename,
-            vis_radix,
-            absolute
+            fine_chans,
+            json,
+            ..
+        }) => {
+            channels::dump_channels(&metafits, &files, fine_chans, json)?;
+            Ok(())
+        }
+        Args::DumpAllData(opts) => match dump_all_data::dump_all_data(&opts, &command_line) {
+            Err(err) if err.is_broken_pipe() => Ok(()),
+            result => Ok(result?),
+        },
+        Args::DumpContext(DumpContextOpt {
+            metafits, files, ..
         }) => {
-            dump_all_data::dump_all_data(&metafits, &files, &dump_filename, vis_radix, absolute)?;
+            dump_context::dump_context(&metafits, &files)?;
+            Ok(())
+        }
+        Args::Flag(opts) => {
+            flag::flag(&opts, &command_line)?;
+            Ok(())
+        }
+        Args::MetafitsInfo(MetafitsInfoOpt { metafits, json }) => {
+            metafits_info::metafits_info(&metafits, json)?;
             Ok(())
         }
+        Args::Peek(opts) => {
+            peek::peek(&opts)?;
+            Ok(())
+        }
+        Args::Plot(PlotOpt::Autos(opts)) => {
+            plot::plot_autos(&opts)?;
+            Ok(())
+        }
+        Args::Plot(PlotOpt::Waterfall(opts)) => {
+            plot::plot_waterfall(&opts)?;
+            Ok(())
+        }
+        Args::RfInputs(RFInputsOpt {
+            metafits,
+            sort_by,
+            json,
+        }) => {
+            rf_inputs::dump_rf_inputs(&metafits, sort_by, json)?;
+            Ok(())
+        }
+        Args::TimeMap(TimeMapOpt {
+            metafits,
+            files,
+            json,
+            ..
+        }) => {
+            time_map::print_time_map(&metafits, &files, json)?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level() {
+        assert_eq!(log_level(0, false), "info");
+        assert_eq!(log_level(1, false), "debug");
+        assert_eq!(log_level(3, false), "trace");
+        // Quiet wins over verbose
+        assert_eq!(log_level(2, true), "warn");
     }
 }
@@ -0,0 +1,165 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given only a metafits file, inspect an observation before its visibilities
+/// are available.
+use crate::serialize::serialize_metafits_context;
+use crate::ScratchpadError;
+use mwalib::MetafitsContext;
+use structopt::StructOpt;
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-metafits-info", author)]
+pub struct MetafitsInfoOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Print the metafits context as JSON instead of a summary
+    #[structopt(short, long)]
+    pub json: bool,
+}
+
+fn format_degrees(degrees: Option<f64>) -> String {
+    match degrees {
+        Some(degrees) => format!("{:.4}", degrees),
+        None => "N/A".to_string(),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn metafits_info<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    json: bool,
+) -> Result<(), ScratchpadError> {
+    let context = MetafitsContext::new(metafits)?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serialize_metafits_context(context))?
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Observation {} \"{}\" ({}), project {}, creator {}",
+        context.obs_id, context.obs_name, context.mode, context.project_id, context.creator
+    );
+
+    println!("Scheduled:");
+    println!(
+        "  start:    {} (gps {:.3}, unix {:.3}, mjd {:.6})",
+        context.sched_start_utc,
+        context.sched_start_gps_time_ms as f64 / 1e3,
+        context.sched_start_unix_time_ms as f64 / 1e3,
+        context.sched_start_mjd
+    );
+    println!(
+        "  end:      {} (gps {:.3}, unix {:.3}, mjd {:.6})",
+        context.sched_end_utc,
+        context.sched_end_gps_time_ms as f64 / 1e3,
+        context.sched_end_unix_time_ms as f64 / 1e3,
+        context.sched_end_mjd
+    );
+    println!(
+        "  duration: {:.3} s, quack time: {:.3} s, good time: unix {:.3}",
+        context.sched_duration_ms as f64 / 1e3,
+        context.quack_time_duration_ms as f64 / 1e3,
+        context.good_time_unix_ms as f64 / 1e3
+    );
+
+    println!("Pointing:");
+    println!(
+        "  tile pointing:  ra {:.4}, dec {:.4} degrees",
+        context.ra_tile_pointing_degrees, context.dec_tile_pointing_degrees
+    );
+    println!(
+        "  phase centre:   ra {}, dec {} degrees",
+        format_degrees(context.ra_phase_center_degrees),
+        format_degrees(context.dec_phase_center_degrees)
+    );
+    println!(
+        "  az {:.4}, alt {:.4}, za {:.4} degrees",
+        context.az_deg, context.alt_deg, context.za_deg
+    );
+    println!(
+        "  lst {:.4} degrees, hour angle {}, grid {} ({})",
+        context.lst_deg, context.hour_angle_string, context.grid_name, context.grid_number
+    );
+    println!(
+        "  sun alt {:.2}, sun {:.2}, moon {:.2}, jupiter {:.2} degrees away",
+        context.sun_alt_deg,
+        context.sun_distance_deg,
+        context.moon_distance_deg,
+        context.jupiter_distance_deg
+    );
+
+    println!("Receivers: {:?}", context.receivers);
+    println!("Delays: {:?}", context.delays);
+    println!(
+        "Global attenuation: {} dB",
+        context.global_analogue_attenuation_db
+    );
+
+    println!("Channels:");
+    println!(
+        "  centre {:.3} MHz, bandwidth {:.3} MHz, {} coarse channels of {} kHz",
+        context.centre_freq_hz as f64 / 1e6,
+        context.obs_bandwidth_hz as f64 / 1e6,
+        context.num_coarse_chans,
+        context.coarse_chan_width_hz as f64 / 1e3
+    );
+    println!(
+        "  {} fine channels of {} kHz per coarse channel, {:.2} s integrations",
+        context.num_corr_fine_chans_per_coarse,
+        context.corr_fine_chan_width_hz as f64 / 1e3,
+        context.corr_int_time_ms as f64 / 1e3
+    );
+
+    println!("Tiles ({}):", context.num_ants);
+    println!(
+        "{:>5} {:>7} {:>10} {:>4} {:>5} {:>10} {:>10} {:>8} {:>8} {:>8}",
+        "ant",
+        "tile_id",
+        "tile_name",
+        "rec",
+        "slot",
+        "north_m",
+        "east_m",
+        "height_m",
+        "flag_x",
+        "flag_y"
+    );
+    for antenna in &context.antennas {
+        println!(
+            "{:>5} {:>7} {:>10} {:>4} {:>5} {:>10.3} {:>10.3} {:>8.3} {:>8} {:>8}",
+            antenna.ant,
+            antenna.tile_id,
+            antenna.tile_name,
+            antenna.rfinput_x.rec_number,
+            antenna.rfinput_x.rec_slot_number,
+            antenna.rfinput_x.north_m,
+            antenna.rfinput_x.east_m,
+            antenna.rfinput_x.height_m,
+            antenna.rfinput_x.flagged,
+            antenna.rfinput_y.flagged
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_degrees() {
+        assert_eq!(format_degrees(Some(-26.70331941)), "-26.7033");
+        assert_eq!(format_degrees(Some(0.)), "0.0000");
+        assert_eq!(format_degrees(None), "N/A");
+    }
+}
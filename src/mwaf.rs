@@ -0,0 +1,327 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Read and write `.mwaf` flag files, one per coarse channel, in the format
+/// written by cotter and Birli: the primary header describes the flags, and a
+/// binary table holds a row of fine channel bits for each scan and baseline.
+use crate::flag::FlagMask;
+use crate::ScratchpadError;
+use fitsio::errors::check_status;
+use fitsio::tables::{ColumnDataType, ColumnDescription};
+use fitsio::FitsFile;
+use mwalib::{fitsio_sys, CorrelatorContext};
+use std::os::raw::c_char;
+use std::path::Path;
+
+/// The flags of one coarse channel, and where they belong in the observation.
+#[derive(Debug, Clone)]
+pub struct MwafFile {
+    /// GPS time of the first scan, in milliseconds.
+    pub gps_time_ms: u64,
+    pub gpubox_number: usize,
+    pub num_ants: usize,
+    pub mask: FlagMask,
+}
+
+impl MwafFile {
+    /// Whether a sample is flagged, given the GPS time of its timestep.
+    /// Timesteps outside the file are unflagged.
+    pub fn is_flagged(
+        &self,
+        gps_time_ms: u64,
+        int_time_ms: u64,
+        baseline: usize,
+        fine_chan: usize,
+    ) -> bool {
+        if gps_time_ms < self.gps_time_ms {
+            return false;
+        }
+        let scan = ((gps_time_ms - self.gps_time_ms) as f64 / int_time_ms as f64).round() as usize;
+        scan < self.mask.num_timesteps && self.mask.fine_chans(scan, baseline)[fine_chan]
+    }
+}
+
+/// Read an `.mwaf` file.
+pub fn read_mwaf<T: AsRef<Path>>(path: &T) -> Result<MwafFile, ScratchpadError> {
+    let mut fptr = FitsFile::open(path)?;
+    let primary_hdu = fptr.primary_hdu()?;
+    // GPSTIME is in seconds, written as an integer by older software
+    let gps_time_ms = (primary_hdu.read_key::<f64>(&mut fptr, "GPSTIME")? * 1000.).round() as u64;
+    let num_fine_chans = primary_hdu.read_key::<i64>(&mut fptr, "NCHANS")? as usize;
+    let num_ants = primary_hdu.read_key::<i64>(&mut fptr, "NANTENNA")? as usize;
+    let num_scans = primary_hdu.read_key::<i64>(&mut fptr, "NSCANS")? as usize;
+    let gpubox_number = primary_hdu.read_key::<i64>(&mut fptr, "GPUBOXNO")? as usize;
+    let num_pols = primary_hdu.read_key::<i64>(&mut fptr, "NPOLS")?;
+    if num_pols != 1 {
+        return Err(ScratchpadError::Mwaf {
+            path: path.as_ref().display().to_string(),
+            reason: format!("has {} pols, only 1 is supported", num_pols),
+        });
+    }
+
+    let num_baselines = num_ants * (num_ants + 1) / 2;
+    let mut mask = FlagMask::new(num_scans, num_baselines, num_fine_chans);
+    fptr.hdu(1)?;
+    let mut row_flags: Vec<c_char> = vec![0; num_fine_chans];
+    for (row_index, row) in mask.flags.chunks_mut(num_fine_chans).enumerate() {
+        let mut status = 0;
+        unsafe {
+            fitsio_sys::ffgcx(
+                fptr.as_raw(),
+                1,
+                row_index as i64 + 1,
+                1,
+                num_fine_chans as i64,
+                row_flags.as_mut_ptr(),
+                &mut status,
+            );
+        }
+        check_status(status)?;
+        for (flag, &row_flag) in row.iter_mut().zip(row_flags.iter()) {
+            *flag = row_flag != 0;
+        }
+    }
+
+    Ok(MwafFile {
+        gps_time_ms,
+        gpubox_number,
+        num_ants,
+        mask,
+    })
+}
+
+/// Check that the scans of an mwaf file start on an integration boundary no
+/// later than the first timestep, and run to at least the last, with GPS
+/// times in milliseconds.
+fn check_scans(
+    mwaf: &MwafFile,
+    first_gps_time_ms: u64,
+    last_gps_time_ms: u64,
+    int_time_ms: u64,
+) -> Result<(), String> {
+    let seconds = |gps_time_ms: u64| gps_time_ms as f64 / 1000.;
+    if mwaf.gps_time_ms > first_gps_time_ms {
+        return Err(format!(
+            "starts at GPS time {:.3}, after the first timestep at {:.3}",
+            seconds(mwaf.gps_time_ms),
+            seconds(first_gps_time_ms)
+        ));
+    }
+    if int_time_ms > 0 && !(first_gps_time_ms - mwaf.gps_time_ms).is_multiple_of(int_time_ms) {
+        return Err(format!(
+            "starts at GPS time {:.3}, which isn't a whole number of {} ms integrations before the first timestep at {:.3}",
+            seconds(mwaf.gps_time_ms),
+            int_time_ms,
+            seconds(first_gps_time_ms)
+        ));
+    }
+    let end_gps_time_ms = mwaf.gps_time_ms + mwaf.mask.num_timesteps as u64 * int_time_ms;
+    if end_gps_time_ms <= last_gps_time_ms {
+        return Err(format!(
+            "has {} scans from GPS time {:.3}, which end before the last timestep at {:.3}",
+            mwaf.mask.num_timesteps,
+            seconds(mwaf.gps_time_ms),
+            seconds(last_gps_time_ms)
+        ));
+    }
+    Ok(())
+}
+
+/// Read `.mwaf` files, and match each to the index of its coarse channel in
+/// `context` by gpubox number. Each must have the observation's antennas and
+/// fine channels, and scans covering its timesteps.
+pub fn read_mwafs_for_context<T: AsRef<Path>>(
+    context: &CorrelatorContext,
+    paths: &[T],
+) -> Result<Vec<Option<MwafFile>>, ScratchpadError> {
+    let mut mwafs: Vec<Option<MwafFile>> = vec![None; context.num_coarse_chans];
+    for path in paths {
+        let mwaf = read_mwaf(path)?;
+        if mwaf.num_ants != context.metafits_context.num_ants
+            || mwaf.mask.num_fine_chans != context.metafits_context.num_corr_fine_chans_per_coarse
+        {
+            return Err(ScratchpadError::Mwaf {
+                path: path.as_ref().display().to_string(),
+                reason: format!(
+                    "has {} antennas and {} fine channels, expected {} and {}",
+                    mwaf.num_ants,
+                    mwaf.mask.num_fine_chans,
+                    context.metafits_context.num_ants,
+                    context.metafits_context.num_corr_fine_chans_per_coarse
+                ),
+            });
+        }
+        if let (Some(first), Some(last)) = (context.timesteps.first(), context.timesteps.last()) {
+            check_scans(
+                &mwaf,
+                first.gps_time_ms,
+                last.gps_time_ms,
+                context.metafits_context.corr_int_time_ms,
+            )
+            .map_err(|reason| ScratchpadError::Mwaf {
+                path: path.as_ref().display().to_string(),
+                reason,
+            })?;
+        }
+        let coarse_chan_index = context
+            .coarse_chans
+            .iter()
+            .position(|coarse_chan| coarse_chan.gpubox_number == mwaf.gpubox_number)
+            .ok_or_else(|| ScratchpadError::Mwaf {
+                path: path.as_ref().display().to_string(),
+                reason: format!(
+                    "is for gpubox {}, which has no gpubox files",
+                    mwaf.gpubox_number
+                ),
+            })?;
+        mwafs[coarse_chan_index] = Some(mwaf);
+    }
+    Ok(mwafs)
+}
+
+/// Write an `.mwaf` file, overwriting any existing file.
+pub fn write_mwaf<T: AsRef<Path>>(path: &T, mwaf: &MwafFile) -> Result<(), ScratchpadError> {
+    let mask = &mwaf.mask;
+    let mut fptr = FitsFile::create(path).overwrite().open()?;
+    let primary_hdu = fptr.primary_hdu()?;
+    primary_hdu.write_key(&mut fptr, "VERSION", "1.0")?;
+    // Written with fixed decimals, as write_key would round it to 9 significant figures
+    let mut status = 0;
+    unsafe {
+        fitsio_sys::ffpkyg(
+            fptr.as_raw(),
+            b"GPSTIME\0".as_ptr() as *const c_char,
+            mwaf.gps_time_ms as f64 / 1000.,
+            3,
+            std::ptr::null(),
+            &mut status,
+        );
+    }
+    check_status(status)?;
+    primary_hdu.write_key(&mut fptr, "NCHANS", mask.num_fine_chans as u64)?;
+    primary_hdu.write_key(&mut fptr, "NANTENNA", mwaf.num_ants as u64)?;
+    primary_hdu.write_key(&mut fptr, "NSCANS", mask.num_timesteps as u64)?;
+    primary_hdu.write_key(&mut fptr, "NPOLS", 1_u64)?;
+    primary_hdu.write_key(&mut fptr, "GPUBOXNO", mwaf.gpubox_number as u64)?;
+    primary_hdu.write_key(
+        &mut fptr,
+        "SOFTWARE",
+        format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+    )?;
+
+    let flags_column = ColumnDescription::new("FLAGS")
+        .with_type(ColumnDataType::Bit)
+        .that_repeats(mask.num_fine_chans)
+        .create()?;
+    fptr.create_table("FLAGS", &[flags_column])?;
+    for (row_index, row) in mask.flags.chunks(mask.num_fine_chans).enumerate() {
+        let mut row_flags: Vec<c_char> = row.iter().map(|&flag| flag as c_char).collect();
+        let mut status = 0;
+        unsafe {
+            fitsio_sys::ffpclx(
+                fptr.as_raw(),
+                1,
+                row_index as i64 + 1,
+                1,
+                mask.num_fine_chans as i64,
+                row_flags.as_mut_ptr(),
+                &mut status,
+            );
+        }
+        check_status(status)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_read_mwaf_with_non_integer_start() {
+        let (num_ants, num_fine_chans, num_scans) = (3, 4, 5);
+        let num_baselines = num_ants * (num_ants + 1) / 2;
+        let mut mask = FlagMask::new(num_scans, num_baselines, num_fine_chans);
+        for scan in 0..num_scans {
+            let index = mask.index(scan, scan % num_baselines, scan % num_fine_chans);
+            mask.flags[index] = true;
+        }
+        let mwaf = MwafFile {
+            gps_time_ms: 1_065_880_128_500,
+            gpubox_number: 7,
+            num_ants,
+            mask,
+        };
+        let path = std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-{}_07.mwaf",
+            std::process::id()
+        ));
+        write_mwaf(&path, &mwaf).unwrap();
+        let read = read_mwaf(&path);
+        std::fs::remove_file(&path).unwrap();
+        let read = read.unwrap();
+
+        assert_eq!(read.gps_time_ms, mwaf.gps_time_ms);
+        assert_eq!(read.gpubox_number, mwaf.gpubox_number);
+        assert_eq!(read.num_ants, mwaf.num_ants);
+        assert_eq!(read.mask.num_timesteps, num_scans);
+        assert_eq!(read.mask.num_baselines, num_baselines);
+        assert_eq!(read.mask.num_fine_chans, num_fine_chans);
+        assert_eq!(read.mask.flags, mwaf.mask.flags);
+
+        // Half second integrations, starting half way through a second
+        let int_time_ms = 500;
+        for scan in 0..num_scans {
+            let gps_time_ms = mwaf.gps_time_ms + scan as u64 * int_time_ms;
+            for baseline in 0..num_baselines {
+                for fine_chan in 0..num_fine_chans {
+                    assert_eq!(
+                        read.is_flagged(gps_time_ms, int_time_ms, baseline, fine_chan),
+                        baseline == scan % num_baselines && fine_chan == scan % num_fine_chans,
+                        "scan {} baseline {} fine chan {}",
+                        scan,
+                        baseline,
+                        fine_chan
+                    );
+                }
+            }
+        }
+        assert!(!read.is_flagged(mwaf.gps_time_ms - int_time_ms, int_time_ms, 0, 0));
+    }
+
+    #[test]
+    fn test_check_scans() {
+        let mwaf = MwafFile {
+            gps_time_ms: 1_065_880_128_000,
+            gpubox_number: 1,
+            num_ants: 2,
+            mask: FlagMask::new(10, 3, 4),
+        };
+        // Ten 2 s scans cover 1065880128 up to 1065880148
+        assert_eq!(
+            check_scans(&mwaf, 1_065_880_128_000, 1_065_880_146_000, 2000),
+            Ok(())
+        );
+        assert_eq!(
+            check_scans(&mwaf, 1_065_880_132_000, 1_065_880_140_000, 2000),
+            Ok(())
+        );
+        assert_eq!(
+            check_scans(&mwaf, 1_065_880_126_000, 1_065_880_140_000, 2000),
+            Err(
+                "starts at GPS time 1065880128.000, after the first timestep at 1065880126.000"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            check_scans(&mwaf, 1_065_880_129_000, 1_065_880_141_000, 2000),
+            Err("starts at GPS time 1065880128.000, which isn't a whole number of 2000 ms integrations before the first timestep at 1065880129.000".to_string())
+        );
+        assert_eq!(
+            check_scans(&mwaf, 1_065_880_128_000, 1_065_880_148_000, 2000),
+            Err("has 10 scans from GPS time 1065880128.000, which end before the last timestep at 1065880148.000".to_string())
+        );
+    }
+}
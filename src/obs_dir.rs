@@ -0,0 +1,360 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Find an observation's metafits and gpubox files in a directory for
+/// `--obs-dir`, instead of listing every file on the command line.
+use crate::config::{find_path_option, insert_args, FILES_KEY};
+use crate::gpubox::GpuboxFile;
+use crate::ScratchpadError;
+use log::warn;
+use mwalib::{CorrelatorVersion, MetafitsContext};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsString;
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+use structopt::clap::App;
+
+/// An observation's files found in a directory, and what looks to be missing.
+#[derive(Debug, Clone)]
+pub struct ObsFiles {
+    pub obs_id: u32,
+    pub metafits: PathBuf,
+    pub gpubox_files: Vec<PathBuf>,
+    /// Gpubox numbers of coarse channels expected from the metafits, with no
+    /// gpubox files.
+    pub missing_chans: Vec<usize>,
+    /// (gpubox number, batch) of batches missing from a channel, up to the
+    /// last batch of any channel.
+    pub missing_batches: Vec<(usize, usize)>,
+    /// Gpubox numbers of files for channels the metafits doesn't expect.
+    pub unexpected_chans: Vec<usize>,
+}
+
+/// Matches the metafits filenames of an observation, e.g. `1065880128.metafits`
+/// or `1065880128_metafits_ppds.fits`.
+fn metafits_re() -> Regex {
+    Regex::new(r"^(?P<obs_id>\d{10})(\.metafits|_metafits(_ppds)?\.fits)$")
+        .expect("metafits filename regex is valid")
+}
+
+/// Find the obsid and metafits of the one observation among `filenames` in
+/// `dir`.
+fn find_metafits(dir: &Path, filenames: &[String]) -> Result<(u32, PathBuf), ScratchpadError> {
+    let metafits_re = metafits_re();
+    let mut metafits_by_obs_id: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for filename in filenames.iter() {
+        if let Some(caps) = metafits_re.captures(filename) {
+            metafits_by_obs_id
+                .entry(caps["obs_id"].parse().map_err(|_| {
+                    ScratchpadError::InvalidArgument(format!(
+                        "{} has an obsid which is out of range",
+                        filename
+                    ))
+                })?)
+                .or_default()
+                .push(filename);
+        }
+    }
+    match metafits_by_obs_id.len() {
+        0 => Err(ScratchpadError::InvalidArgument(format!(
+            "no metafits in {}",
+            dir.display()
+        ))),
+        1 => {
+            let (&obs_id, metafits) = metafits_by_obs_id.iter().next().unwrap();
+            Ok((obs_id, dir.join(metafits[0])))
+        }
+        _ => Err(ScratchpadError::InvalidArgument(format!(
+            "{} has metafits for more than one observation: {:?}",
+            dir.display(),
+            metafits_by_obs_id.keys().collect::<Vec<_>>()
+        ))),
+    }
+}
+
+/// Find the gpubox files of `obs_id` among `filenames` in `dir`, and the
+/// correlator version their names are from.
+fn find_gpubox_files(
+    dir: &Path,
+    filenames: &[String],
+    obs_id: u32,
+) -> (Vec<GpuboxFile>, CorrelatorVersion) {
+    let metafits_re = metafits_re();
+    let obs_prefix = format!("{}_", obs_id);
+    let gpubox_files: Vec<GpuboxFile> = filenames
+        .iter()
+        .filter(|filename| filename.starts_with(&obs_prefix))
+        .filter(|filename| !metafits_re.is_match(filename))
+        .filter_map(|filename| GpuboxFile::from_path(&dir.join(filename)).ok())
+        .collect();
+
+    // Old legacy files have no batch number
+    let old_legacy_re =
+        Regex::new(r"_gpubox\d{2}\.fits$").expect("old legacy filename regex is valid");
+    let corr_version = if gpubox_files
+        .iter()
+        .all(|gpubox_file| !gpubox_file.filename.contains("_gpubox"))
+    {
+        CorrelatorVersion::V2
+    } else if gpubox_files
+        .iter()
+        .any(|gpubox_file| old_legacy_re.is_match(&gpubox_file.filename))
+    {
+        CorrelatorVersion::OldLegacy
+    } else {
+        CorrelatorVersion::Legacy
+    };
+    (gpubox_files, corr_version)
+}
+
+impl ObsFiles {
+    /// Find the metafits of the one observation in `dir`, and the legacy
+    /// (`_gpuboxNN_NN.fits`, `_gpuboxNN.fits`) or MWAX (`_chNNN_NNN.fits`)
+    /// gpubox files of the same obsid.
+    pub fn find<T: AsRef<Path>>(dir: &T) -> Result<Self, ScratchpadError> {
+        let dir = dir.as_ref();
+        let mut filenames: Vec<String> = read_dir(dir)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+            .collect::<Result<_, std::io::Error>>()?;
+        filenames.sort();
+
+        let (obs_id, metafits) = find_metafits(dir, &filenames)?;
+        let (gpubox_files, corr_version) = find_gpubox_files(dir, &filenames, obs_id);
+        let expected_chans: BTreeSet<usize> = MetafitsContext::new(&metafits)?
+            .get_expected_coarse_channels(corr_version)?
+            .iter()
+            .map(|coarse_chan| coarse_chan.gpubox_number)
+            .collect();
+        Ok(Self::new(obs_id, metafits, gpubox_files, &expected_chans))
+    }
+
+    /// Check `gpubox_files` against the gpubox numbers of the coarse channels
+    /// the metafits expects.
+    fn new(
+        obs_id: u32,
+        metafits: PathBuf,
+        gpubox_files: Vec<GpuboxFile>,
+        expected_chans: &BTreeSet<usize>,
+    ) -> Self {
+        let mut batches_by_chan: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for gpubox_file in gpubox_files.iter() {
+            batches_by_chan
+                .entry(gpubox_file.channel_identifier)
+                .or_default()
+                .insert(gpubox_file.batch_number);
+        }
+        let last_batch = batches_by_chan
+            .values()
+            .filter_map(|batches| batches.iter().next_back())
+            .max()
+            .cloned();
+
+        ObsFiles {
+            obs_id,
+            metafits,
+            missing_chans: if gpubox_files.is_empty() {
+                vec![]
+            } else {
+                expected_chans
+                    .iter()
+                    .filter(|chan| !batches_by_chan.contains_key(chan))
+                    .cloned()
+                    .collect()
+            },
+            missing_batches: batches_by_chan
+                .iter()
+                .flat_map(|(&chan, batches)| {
+                    (0..=last_batch.unwrap_or(0))
+                        .filter(move |batch| !batches.contains(batch))
+                        .map(move |batch| (chan, batch))
+                })
+                .collect(),
+            unexpected_chans: batches_by_chan
+                .keys()
+                .filter(|chan| !expected_chans.contains(chan))
+                .cloned()
+                .collect(),
+            gpubox_files: gpubox_files
+                .into_iter()
+                .map(|gpubox_file| PathBuf::from(gpubox_file.filename))
+                .collect(),
+        }
+    }
+
+    /// Log a warning for anything that looks to be missing.
+    pub fn warn_missing(&self) {
+        if self.gpubox_files.is_empty() {
+            warn!("No gpubox files found for obsid {}", self.obs_id);
+        }
+        for chan in self.missing_chans.iter() {
+            warn!("No gpubox files found for channel {}", chan);
+        }
+        for (chan, batch) in self.missing_batches.iter() {
+            warn!("Batch {} of channel {} is missing", batch, chan);
+        }
+        for chan in self.unexpected_chans.iter() {
+            warn!(
+                "Found gpubox files for channel {}, which the metafits doesn't expect",
+                chan
+            );
+        }
+    }
+}
+
+/// Find the files of the observation in the directory given with `--obs-dir`,
+/// if any, and insert them as the metafits and gpubox files with
+/// `insert_args`, so ones given on the command line still win.
+pub fn args_with_obs_dir(
+    app: App,
+    args: Vec<OsString>,
+) -> Result<(Vec<OsString>, Option<ObsFiles>), ScratchpadError> {
+    let dir = match find_path_option(&args, "--obs-dir") {
+        Some(dir) => dir,
+        None => return Ok((args, None)),
+    };
+    let obs_files = ObsFiles::find(&dir)?;
+    let mut metafits_arg = OsString::from("--metafits=");
+    metafits_arg.push(&obs_files.metafits);
+    let option_args = vec![
+        ("metafits".to_string(), vec![metafits_arg]),
+        (
+            FILES_KEY.to_string(),
+            obs_files
+                .gpubox_files
+                .iter()
+                .map(|path| path.clone().into_os_string())
+                .collect(),
+        ),
+    ];
+    Ok((insert_args(app, args, option_args), Some(obs_files)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filenames(filenames: &[&str]) -> Vec<String> {
+        filenames
+            .iter()
+            .map(|filename| filename.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_find_metafits() {
+        let dir = Path::new("obs");
+        for metafits in [
+            "1065880128.metafits",
+            "1065880128_metafits.fits",
+            "1065880128_metafits_ppds.fits",
+        ] {
+            let names = filenames(&[metafits, "1065880128_20131015134830_gpubox01_00.fits"]);
+            let (obs_id, path) = find_metafits(dir, &names).unwrap();
+            assert_eq!(obs_id, 1065880128);
+            assert_eq!(path, dir.join(metafits));
+        }
+    }
+
+    #[test]
+    fn test_find_metafits_none() {
+        let names = filenames(&["1065880128_20131015134830_gpubox01_00.fits", "notes.txt"]);
+        assert!(find_metafits(Path::new("obs"), &names).is_err());
+    }
+
+    #[test]
+    fn test_find_metafits_many_obs_ids() {
+        let names = filenames(&["1065880128.metafits", "1065880139_metafits_ppds.fits"]);
+        assert!(find_metafits(Path::new("obs"), &names).is_err());
+    }
+
+    #[test]
+    fn test_find_gpubox_files_legacy() {
+        let dir = Path::new("obs");
+        let names = filenames(&[
+            "1065880128.metafits",
+            "1065880128_20131015134830_gpubox01_00.fits",
+            "1065880128_20131015134830_gpubox01_01.fits",
+            "1065880128_20131015134930_gpubox02_00.fits",
+            "1065880128_01.mwaf",
+            "1065880139_20131015134830_gpubox01_00.fits",
+        ]);
+        let (gpubox_files, corr_version) = find_gpubox_files(dir, &names, 1065880128);
+        assert_eq!(corr_version, CorrelatorVersion::Legacy);
+        let found: Vec<(usize, usize)> = gpubox_files
+            .iter()
+            .map(|gpubox_file| (gpubox_file.channel_identifier, gpubox_file.batch_number))
+            .collect();
+        assert_eq!(found, vec![(1, 0), (1, 1), (2, 0)]);
+        assert_eq!(
+            gpubox_files[0].filename,
+            dir.join(&names[1]).to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_find_gpubox_files_old_legacy() {
+        let names = filenames(&[
+            "1065880128_20131015134830_gpubox01.fits",
+            "1065880128_20131015134830_gpubox02.fits",
+        ]);
+        let (gpubox_files, corr_version) = find_gpubox_files(Path::new("obs"), &names, 1065880128);
+        assert_eq!(corr_version, CorrelatorVersion::OldLegacy);
+        assert_eq!(gpubox_files.len(), 2);
+    }
+
+    #[test]
+    fn test_find_gpubox_files_mwax() {
+        let names = filenames(&[
+            "1297526432_metafits.fits",
+            "1297526432_20210216160014_ch117_000.fits",
+            "1297526432_20210216160014_ch118_000.fits",
+        ]);
+        let (gpubox_files, corr_version) = find_gpubox_files(Path::new("obs"), &names, 1297526432);
+        assert_eq!(corr_version, CorrelatorVersion::V2);
+        let chans: Vec<usize> = gpubox_files
+            .iter()
+            .map(|gpubox_file| gpubox_file.channel_identifier)
+            .collect();
+        assert_eq!(chans, vec![117, 118]);
+    }
+
+    #[test]
+    fn test_obs_files_missing() {
+        let names = filenames(&[
+            "1065880128_20131015134830_gpubox01_00.fits",
+            "1065880128_20131015134830_gpubox01_01.fits",
+            "1065880128_20131015134830_gpubox01_02.fits",
+            "1065880128_20131015134830_gpubox02_00.fits",
+            "1065880128_20131015134830_gpubox02_02.fits",
+            "1065880128_20131015134830_gpubox25_00.fits",
+        ]);
+        let (gpubox_files, _) = find_gpubox_files(Path::new("obs"), &names, 1065880128);
+        let expected_chans: BTreeSet<usize> = (1..=3).collect();
+        let obs_files = ObsFiles::new(
+            1065880128,
+            PathBuf::from("obs/1065880128.metafits"),
+            gpubox_files,
+            &expected_chans,
+        );
+        assert_eq!(obs_files.gpubox_files.len(), 6);
+        assert_eq!(obs_files.missing_chans, vec![3]);
+        assert_eq!(obs_files.missing_batches, vec![(2, 1), (25, 1), (25, 2)]);
+        assert_eq!(obs_files.unexpected_chans, vec![25]);
+    }
+
+    #[test]
+    fn test_obs_files_no_gpubox_files() {
+        let expected_chans: BTreeSet<usize> = (1..=24).collect();
+        let obs_files = ObsFiles::new(
+            1065880128,
+            PathBuf::from("obs/1065880128.metafits"),
+            vec![],
+            &expected_chans,
+        );
+        assert!(obs_files.missing_chans.is_empty());
+        assert!(obs_files.missing_batches.is_empty());
+        assert!(obs_files.unexpected_chans.is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Buffered, optionally compressed writers for dump files.
+use crate::ScratchpadError;
+use flate2::write::GzEncoder;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+const BUFFER_SIZE: usize = 1 << 20;
+
+/// How to compress a dump.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl FromStr for Compression {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Compression::None),
+            "gzip" => Ok(Compression::Gzip),
+            "zstd" => Ok(Compression::Zstd),
+            _ => Err(ScratchpadError::UnknownChoice {
+                kind: "compression",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Compression {
+    /// Guess the compression from a filename ending in `.gz` or `.zst`.
+    pub fn from_path<T: AsRef<Path>>(path: &T) -> Self {
+        match path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("gz") => Compression::Gzip,
+            Some("zst") | Some("zstd") => Compression::Zstd,
+            _ => Compression::None,
+        }
+    }
+}
+
+/// A buffered writer, compressing on the way out if asked to. Call `finish`
+/// when done so compressed streams are terminated and errors are reported.
+pub enum DumpWriter {
+    Plain(BufWriter<Box<dyn Write + Send>>),
+    Gzip(GzEncoder<BufWriter<Box<dyn Write + Send>>>),
+    Zstd(zstd::Encoder<'static, BufWriter<Box<dyn Write + Send>>>),
+}
+
+impl DumpWriter {
+    /// Wrap `inner` in a buffer and `compression`.
+    pub fn new(
+        inner: Box<dyn Write + Send>,
+        compression: Compression,
+    ) -> Result<Self, ScratchpadError> {
+        let buffered = BufWriter::with_capacity(BUFFER_SIZE, inner);
+        Ok(match compression {
+            Compression::None => DumpWriter::Plain(buffered),
+            Compression::Gzip => {
+                DumpWriter::Gzip(GzEncoder::new(buffered, flate2::Compression::default()))
+            }
+            Compression::Zstd => DumpWriter::Zstd(zstd::Encoder::new(buffered, 0)?),
+        })
+    }
+
+    /// Create a file, compressed by `compression`, or by its extension if not given.
+    /// A path of `-` writes to stdout.
+    pub fn create<T: AsRef<Path>>(
+        path: &T,
+        compression: Option<Compression>,
+    ) -> Result<Self, ScratchpadError> {
+        if path.as_ref() == Path::new("-") {
+            return Self::stdout(compression);
+        }
+        let compression = compression.unwrap_or_else(|| Compression::from_path(path));
+        Self::new(Box::new(File::create(path)?), compression)
+    }
+
+    /// Write to stdout, uncompressed unless `compression` is given.
+    pub fn stdout(compression: Option<Compression>) -> Result<Self, ScratchpadError> {
+        Self::new(
+            Box::new(std::io::stdout()),
+            compression.unwrap_or(Compression::None),
+        )
+    }
+
+    /// Flush the buffer and terminate any compressed stream.
+    pub fn finish(self) -> Result<(), ScratchpadError> {
+        let mut buffered = match self {
+            DumpWriter::Plain(buffered) => buffered,
+            DumpWriter::Gzip(encoder) => encoder.finish()?,
+            DumpWriter::Zstd(encoder) => encoder.finish()?,
+        };
+        buffered.flush()?;
+        Ok(())
+    }
+}
+
+impl Write for DumpWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            DumpWriter::Plain(writer) => writer.write(buf),
+            DumpWriter::Gzip(writer) => writer.write(buf),
+            DumpWriter::Zstd(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            DumpWriter::Plain(writer) => writer.flush(),
+            DumpWriter::Gzip(writer) => writer.flush(),
+            DumpWriter::Zstd(writer) => writer.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    const CONTENTS: &str = "coarse_chan,timestep\n0,0\n0,1\n";
+
+    fn test_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-output-{}-{}",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// Write `CONTENTS` to `path` and return the raw bytes of the file.
+    fn write_contents(path: &Path, compression: Option<Compression>) -> Vec<u8> {
+        let mut writer = DumpWriter::create(&path, compression).unwrap();
+        writer.write_all(CONTENTS.as_bytes()).unwrap();
+        writer.finish().unwrap();
+        let bytes = std::fs::read(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_compression_from_path() {
+        assert_eq!(Compression::from_path(&"dump.csv"), Compression::None);
+        assert_eq!(Compression::from_path(&"dump.csv.gz"), Compression::Gzip);
+        assert_eq!(Compression::from_path(&"dump.csv.zst"), Compression::Zstd);
+        assert_eq!(Compression::from_path(&"dump.json.zstd"), Compression::Zstd);
+        assert_eq!(Compression::from_path(&"dump"), Compression::None);
+    }
+
+    #[test]
+    fn test_compression_from_str() {
+        assert_eq!("gzip".parse::<Compression>().unwrap(), Compression::Gzip);
+        assert!(matches!(
+            "bz2".parse::<Compression>(),
+            Err(ScratchpadError::UnknownChoice { .. })
+        ));
+    }
+
+    #[test]
+    fn test_plain_round_trip() {
+        let bytes = write_contents(&test_path("plain.csv"), None);
+        assert_eq!(bytes, CONTENTS.as_bytes());
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let bytes = write_contents(&test_path("dump.csv.gz"), None);
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(&bytes[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, CONTENTS);
+    }
+
+    #[test]
+    fn test_zstd_round_trip() {
+        let bytes = write_contents(&test_path("dump.csv.zst"), None);
+        assert_eq!(zstd::decode_all(&bytes[..]).unwrap(), CONTENTS.as_bytes());
+    }
+
+    #[test]
+    fn test_compression_overrides_extension() {
+        let bytes = write_contents(&test_path("dump.csv.gz"), Some(Compression::Zstd));
+        assert_eq!(zstd::decode_all(&bytes[..]).unwrap(), CONTENTS.as_bytes());
+        let bytes = write_contents(&test_path("dump.csv.zst"), Some(Compression::None));
+        assert_eq!(bytes, CONTENTS.as_bytes());
+    }
+
+    #[test]
+    fn test_finish_flushes() {
+        let path = test_path("flush.csv");
+        let mut writer = DumpWriter::create(&path, None).unwrap();
+        writer.write_all(CONTENTS.as_bytes()).unwrap();
+        // Still in the buffer
+        let before = std::fs::read(&path).unwrap();
+        writer.finish().unwrap();
+        let after = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(before.is_empty());
+        assert_eq!(after, CONTENTS.as_bytes());
+    }
+}
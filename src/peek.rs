@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given gpubox files, print a quick-look spectrum or waterfall of a baseline
+/// straight to the terminal, for when there's no way to view an image.
+use crate::plot::{read_baseline_grid, BaselineGrid, VisPol, VisValue};
+use crate::ScratchpadError;
+use mwalib::CorrelatorContext;
+use structopt::StructOpt;
+
+const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Average visibilities over `timesteps` and runs of fine channels into
+/// `width` columns. Amplitudes are averaged sample by sample, as the complex
+/// mean of a fringing baseline decorrelates, like the autos of `plot`. Phases
+/// are of the complex mean, so they don't wrap.
+fn bin_columns(
+    grid: &BaselineGrid,
+    timesteps: std::ops::Range<usize>,
+    value: VisValue,
+    width: usize,
+) -> Vec<f32> {
+    let num_fine_chans = grid.fine_chan_freqs_hz.len();
+    (0..width)
+        .map(|column| {
+            let fine_chans = column * num_fine_chans / width..(column + 1) * num_fine_chans / width;
+            let mut sum = [0_f32; 2];
+            let mut amplitude_sum = 0_f32;
+            let mut count = 0;
+            for timestep_index in timesteps.clone() {
+                for fine_chan_index in fine_chans.clone() {
+                    let re_im = grid.vis[timestep_index * num_fine_chans + fine_chan_index];
+                    sum[0] += re_im[0];
+                    sum[1] += re_im[1];
+                    amplitude_sum += VisValue::Amplitude.of(re_im);
+                    count += 1;
+                }
+            }
+            if count == 0 {
+                return f32::NAN;
+            }
+            match value {
+                VisValue::Amplitude => amplitude_sum / count as f32,
+                VisValue::Phase => value.of(sum),
+            }
+        })
+        .collect()
+}
+
+/// The range of the finite values, or (-π, π) for phases.
+fn value_range(value: VisValue, float_vals: &[f32]) -> (f32, f32) {
+    match value {
+        VisValue::Amplitude => float_vals
+            .iter()
+            .filter(|float_val| float_val.is_finite())
+            .fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(min, max), &float_val| (min.min(float_val), max.max(float_val)),
+            ),
+        VisValue::Phase => (-std::f32::consts::PI, std::f32::consts::PI),
+    }
+}
+
+/// Scale a value between `min` and `max` to a level from 0 to `num_levels`.
+fn level(float_val: f32, min: f32, max: f32, num_levels: usize) -> usize {
+    if !float_val.is_finite() || max <= min {
+        return 0;
+    }
+    (((float_val - min) / (max - min)) * num_levels as f32)
+        .round()
+        .clamp(0., num_levels as f32) as usize
+}
+
+/// Render a time-averaged spectrum as a bar chart `height` rows tall.
+pub fn spectrum_lines(
+    grid: &BaselineGrid,
+    value: VisValue,
+    width: usize,
+    height: usize,
+) -> Vec<String> {
+    let float_vals = bin_columns(grid, 0..grid.num_timesteps, value, width);
+    let (min, max) = value_range(value, &float_vals);
+    let num_levels = height * (BARS.len() - 1);
+    let levels: Vec<usize> = float_vals
+        .iter()
+        .map(|&float_val| level(float_val, min, max, num_levels))
+        .collect();
+    (0..height)
+        .rev()
+        .map(|row| {
+            levels
+                .iter()
+                .map(|&level| {
+                    BARS[level
+                        .saturating_sub(row * (BARS.len() - 1))
+                        .min(BARS.len() - 1)]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Render a waterfall with a row per timestep, shaded by value.
+pub fn waterfall_lines(grid: &BaselineGrid, value: VisValue, width: usize) -> Vec<String> {
+    let rows: Vec<Vec<f32>> = (0..grid.num_timesteps)
+        .map(|timestep_index| bin_columns(grid, timestep_index..timestep_index + 1, value, width))
+        .collect();
+    let (min, max) = value_range(value, &rows.concat());
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&float_val| SHADES[level(float_val, min, max, SHADES.len() - 1)])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-peek", author)]
+pub struct PeekOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Baseline index, as in the baselines subcommand
+    #[structopt(short, long)]
+    pub baseline: usize,
+
+    /// Visibility pol to show
+    #[structopt(short, long, default_value = "xx", possible_values = &["xx", "xy", "yx", "yy"])]
+    pub pol: VisPol,
+
+    /// Part of each visibility to show
+    #[structopt(long, default_value = "amplitude", possible_values = &["amplitude", "phase"])]
+    pub value: VisValue,
+
+    /// Show a waterfall with a row per timestep instead of the time-averaged spectrum
+    #[structopt(long)]
+    pub waterfall: bool,
+
+    /// Width in characters
+    #[structopt(long, default_value = "80")]
+    pub width: usize,
+
+    /// Height of the spectrum in lines
+    #[structopt(long, default_value = "8")]
+    pub height: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn peek(opts: &PeekOpt) -> Result<(), ScratchpadError> {
+    if opts.width == 0 || opts.height == 0 {
+        return Err(ScratchpadError::InvalidArgument(
+            "width and height must be at least 1".to_string(),
+        ));
+    }
+    let mut context = CorrelatorContext::new(&opts.metafits, &opts.files)?;
+    let grid = read_baseline_grid(&mut context, opts.baseline, opts.pol)?;
+    let width = opts.width.min(grid.fine_chan_freqs_hz.len());
+    let baseline = &context.metafits_context.baselines[opts.baseline];
+    println!(
+        "{} baseline {} ({} - {}) {:?}",
+        context.metafits_context.obs_id,
+        opts.baseline,
+        context.metafits_context.antennas[baseline.ant1_index].tile_name,
+        context.metafits_context.antennas[baseline.ant2_index].tile_name,
+        opts.value
+    );
+    let lines = if opts.waterfall {
+        waterfall_lines(&grid, opts.value, width)
+    } else {
+        spectrum_lines(&grid, opts.value, width, opts.height)
+    };
+    for line in lines {
+        println!("{}", line);
+    }
+    if let (Some(first), Some(last)) = (
+        grid.fine_chan_freqs_hz.first(),
+        grid.fine_chan_freqs_hz.last(),
+    ) {
+        println!(
+            "{:<width$}{:>8.3} MHz",
+            format!("{:.3} MHz", first / 1e6),
+            last / 1e6,
+            width = width.saturating_sub(12)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A grid of one baseline with 4 fine channels.
+    fn test_grid(vis: Vec<[f32; 2]>) -> BaselineGrid {
+        BaselineGrid {
+            num_timesteps: vis.len() / 4,
+            fine_chan_freqs_hz: vec![139.0e6, 139.04e6, 139.08e6, 139.12e6],
+            fine_chan_width_hz: 40e3,
+            vis,
+        }
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0., 0., 4., 8), 0);
+        assert_eq!(level(4., 0., 4., 8), 8);
+        assert_eq!(level(1.2, 0., 4., 8), 2);
+        assert_eq!(level(1.3, 0., 4., 8), 3);
+        assert_eq!(level(5., 0., 4., 8), 8);
+        assert_eq!(level(-1., 0., 4., 8), 0);
+        assert_eq!(level(f32::NAN, 0., 4., 8), 0);
+        assert_eq!(level(2., 2., 2., 8), 0);
+    }
+
+    #[test]
+    fn test_bin_columns_averages_amplitudes() {
+        // A baseline fringing through half a turn between timesteps
+        let grid = test_grid(vec![
+            [1., 0.],
+            [0., 2.],
+            [3., 0.],
+            [0., 4.],
+            [-1., 0.],
+            [0., -2.],
+            [-3., 0.],
+            [0., -4.],
+        ]);
+        assert_eq!(
+            bin_columns(&grid, 0..2, VisValue::Amplitude, 4),
+            vec![1., 2., 3., 4.]
+        );
+        assert_eq!(
+            bin_columns(&grid, 0..2, VisValue::Amplitude, 2),
+            vec![1.5, 3.5]
+        );
+        assert_eq!(
+            bin_columns(&grid, 1..2, VisValue::Phase, 4),
+            vec![
+                std::f32::consts::PI,
+                -std::f32::consts::FRAC_PI_2,
+                std::f32::consts::PI,
+                -std::f32::consts::FRAC_PI_2
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spectrum_lines() {
+        let grid = test_grid(vec![[0., 0.], [1., 0.], [2., 0.], [3., 0.]]);
+        // 16 levels over 2 lines: 0, 5.3, 10.7 and 16
+        assert_eq!(
+            spectrum_lines(&grid, VisValue::Amplitude, 4, 2),
+            vec!["  ▃█", " ▅██"]
+        );
+    }
+
+    #[test]
+    fn test_waterfall_lines() {
+        let grid = test_grid(vec![
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [3., 0.],
+            [4., 0.],
+            [4., 0.],
+            [0., 0.],
+            [0., 0.],
+        ]);
+        assert_eq!(
+            waterfall_lines(&grid, VisValue::Amplitude, 4),
+            vec![" ░▒▓", "██  "]
+        );
+        assert_eq!(
+            waterfall_lines(&grid, VisValue::Amplitude, 2),
+            vec!["░▓", "█ "]
+        );
+    }
+}
@@ -0,0 +1,689 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given gpubox files, render quick-look plots to PNG or SVG, chosen by the
+/// output file extension.
+use crate::vis::{VisCorrections, VisFlagging, VisPlan, VisRecord, VisSelection};
+use crate::ScratchpadError;
+use mwalib::CorrelatorContext;
+use plotters::coord::Shift;
+use plotters::drawing::DrawingAreaErrorKind;
+use plotters::prelude::*;
+use plotters::style::register_font;
+use std::f32::consts::PI;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use structopt::StructOpt;
+
+const PLOT_SIZE: (u32, u32) = (1200, 800);
+const AUTOS_PANEL_SIZE: (u32, u32) = (250, 180);
+/// DejaVu Sans, for labels unless `--font` is given.
+const DEFAULT_FONT: &[u8] = include_bytes!("../assets/DejaVuSans.ttf");
+/// A font read from `--font`, kept for as long as plotters might use it.
+static USER_FONT: OnceLock<(PathBuf, Vec<u8>)> = OnceLock::new();
+
+/// A visibility pol, parsed from `xx`, `xy`, `yx` or `yy`.
+#[derive(Debug, Clone, Copy)]
+pub struct VisPol(pub usize);
+
+impl FromStr for VisPol {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "xx" => Ok(VisPol(0)),
+            "xy" => Ok(VisPol(1)),
+            "yx" => Ok(VisPol(2)),
+            "yy" => Ok(VisPol(3)),
+            _ => Err(ScratchpadError::UnknownChoice {
+                kind: "pol",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Which part of each visibility to plot.
+#[derive(Debug, Clone, Copy)]
+pub enum VisValue {
+    Amplitude,
+    Phase,
+}
+
+impl FromStr for VisValue {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "amplitude" => Ok(VisValue::Amplitude),
+            "phase" => Ok(VisValue::Phase),
+            _ => Err(ScratchpadError::UnknownChoice {
+                kind: "visibility value",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl VisValue {
+    pub fn of(self, re_im: [f32; 2]) -> f32 {
+        match self {
+            VisValue::Amplitude => (re_im[0] * re_im[0] + re_im[1] * re_im[1]).sqrt(),
+            VisValue::Phase => re_im[1].atan2(re_im[0]),
+        }
+    }
+}
+
+/// The visibilities of one baseline and pol across every coarse channel,
+/// ordered [timestep][fine_chan].
+#[derive(Debug, Clone)]
+pub struct BaselineGrid {
+    pub num_timesteps: usize,
+    pub fine_chan_freqs_hz: Vec<f64>,
+    pub fine_chan_width_hz: f64,
+    pub vis: Vec<[f32; 2]>,
+}
+
+/// The frequency of every fine channel, across every coarse channel.
+fn all_fine_chan_freqs_hz(context: &CorrelatorContext) -> Vec<f64> {
+    context
+        .coarse_chans
+        .iter()
+        .flat_map(|coarse_chan| {
+            crate::channels::fine_chan_freqs_hz(coarse_chan, &context.metafits_context)
+        })
+        .collect()
+}
+
+impl BaselineGrid {
+    /// Place `pol` of each record of one baseline on the grid, by timestep and
+    /// fine channel across every coarse channel.
+    fn from_records<I: Iterator<Item = Result<VisRecord, ScratchpadError>>>(
+        records: I,
+        pol: VisPol,
+        num_timesteps: usize,
+        num_fine_chans_per_coarse: usize,
+        fine_chan_freqs_hz: Vec<f64>,
+        fine_chan_width_hz: f64,
+    ) -> Result<Self, ScratchpadError> {
+        let num_fine_chans = fine_chan_freqs_hz.len();
+        let mut vis = vec![[0_f32; 2]; num_timesteps * num_fine_chans];
+        for record in records {
+            let record = record?;
+            let re_im = record.vis[pol.0];
+            vis[record.timestep * num_fine_chans
+                + record.coarse_chan * num_fine_chans_per_coarse
+                + record.fine_chan] = [re_im.re, re_im.im];
+        }
+        Ok(BaselineGrid {
+            num_timesteps,
+            fine_chan_freqs_hz,
+            fine_chan_width_hz,
+            vis,
+        })
+    }
+}
+
+/// Read every timestep and coarse channel of a baseline and pol.
+pub fn read_baseline_grid(
+    context: &mut CorrelatorContext,
+    baseline_index: usize,
+    pol: VisPol,
+) -> Result<BaselineGrid, ScratchpadError> {
+    let plan = VisPlan::new(
+        context,
+        VisSelection {
+            baselines: vec![baseline_index],
+            ..Default::default()
+        },
+        VisCorrections::default(),
+        VisFlagging::default(),
+    )?;
+    let num_timesteps = context.num_timesteps;
+    let num_fine_chans_per_coarse = context.metafits_context.num_corr_fine_chans_per_coarse;
+    let fine_chan_freqs_hz = all_fine_chan_freqs_hz(context);
+    let fine_chan_width_hz = context.metafits_context.corr_fine_chan_width_hz as f64;
+    BaselineGrid::from_records(
+        plan.records(context),
+        pol,
+        num_timesteps,
+        num_fine_chans_per_coarse,
+        fine_chan_freqs_hz,
+        fine_chan_width_hz,
+    )
+}
+
+/// Register a TrueType font for labels, which bitmap and SVG output both need
+/// to lay out text, from `path` or the embedded DejaVu Sans.
+/// Only one font file can be loaded, as plotters keeps the font for good.
+fn load_font<T: AsRef<Path>>(path: Option<&T>) -> Result<(), ScratchpadError> {
+    let (bytes, name): (&'static [u8], String) = match path {
+        Some(path) => {
+            let path = path.as_ref();
+            if USER_FONT.get().is_none() {
+                // If another thread gets there first, its font is checked below
+                let _ = USER_FONT.set((path.to_path_buf(), std::fs::read(path)?));
+            }
+            match USER_FONT.get() {
+                Some((font_path, bytes)) if font_path == path => {
+                    (bytes.as_slice(), path.display().to_string())
+                }
+                _ => {
+                    return Err(ScratchpadError::InvalidArgument(format!(
+                        "can't load font {}, as another font is already loaded",
+                        path.display()
+                    )))
+                }
+            }
+        }
+        None => (DEFAULT_FONT, "DejaVu Sans".to_string()),
+    };
+    register_font("sans-serif", FontStyle::Normal, bytes)
+        .map_err(|_| ScratchpadError::InvalidFont(name))
+}
+
+/// The range of frequencies, which needn't be in order, widened by
+/// `margin_mhz` either side.
+fn freq_range_mhz(freqs_mhz: &[f64], margin_mhz: f64) -> Range<f64> {
+    let (min, max) = freqs_mhz.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), &freq_mhz| (min.min(freq_mhz), max.max(freq_mhz)),
+    );
+    min - margin_mhz..max + margin_mhz
+}
+
+/// The colour of a value: viridis between `min` and `max` for amplitudes, and
+/// a cyclic hue for phases.
+fn value_colour(value: VisValue, float_val: f32, min: f32, max: f32) -> RGBAColor {
+    match value {
+        VisValue::Amplitude if max > min => ViridisRGB
+            .get_color_normalized(float_val, min, max)
+            .to_rgba(),
+        VisValue::Amplitude => ViridisRGB.get_color(0.5).to_rgba(),
+        VisValue::Phase => HSLColor(((float_val + PI) / (2. * PI)) as f64, 0.8, 0.5).to_rgba(),
+    }
+}
+
+fn draw_waterfall<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    grid: &BaselineGrid,
+    value: VisValue,
+    caption: &str,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let num_fine_chans = grid.fine_chan_freqs_hz.len();
+    let float_vals: Vec<f32> = grid.vis.iter().map(|&re_im| value.of(re_im)).collect();
+    let (min, max) = match value {
+        VisValue::Amplitude => float_vals
+            .iter()
+            .filter(|float_val| float_val.is_finite())
+            .fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(min, max), &float_val| (min.min(float_val), max.max(float_val)),
+            ),
+        VisValue::Phase => (-PI, PI),
+    };
+    let freqs_mhz: Vec<f64> = grid
+        .fine_chan_freqs_hz
+        .iter()
+        .map(|freq_hz| freq_hz / 1e6)
+        .collect();
+    let half_width_mhz = grid.fine_chan_width_hz / 2e6;
+
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(caption, ("sans-serif", 20))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(
+            freq_range_mhz(&freqs_mhz, half_width_mhz),
+            0_f64..grid.num_timesteps as f64,
+        )?;
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_desc("Frequency (MHz)")
+        .y_desc("Timestep")
+        .draw()?;
+    chart.draw_series(
+        float_vals
+            .iter()
+            .enumerate()
+            .filter(|(_, float_val)| float_val.is_finite())
+            .map(|(index, &float_val)| {
+                let timestep = (index / num_fine_chans) as f64;
+                let freq_mhz = freqs_mhz[index % num_fine_chans];
+                Rectangle::new(
+                    [
+                        (freq_mhz - half_width_mhz, timestep),
+                        (freq_mhz + half_width_mhz, timestep + 1.),
+                    ],
+                    value_colour(value, float_val, min, max).filled(),
+                )
+            }),
+    )?;
+    root.present()?;
+    Ok(())
+}
+
+/// The XX and YY autocorrelation amplitude of each tile across every coarse
+/// channel, averaged over time.
+#[derive(Debug, Clone)]
+pub struct AutoSpectra {
+    pub fine_chan_freqs_hz: Vec<f64>,
+    /// Ordered [antenna][xx, yy][fine_chan].
+    pub spectra: Vec<[Vec<f64>; 2]>,
+}
+
+/// Read every timestep and coarse channel, averaging the autocorrelations.
+pub fn read_auto_spectra(context: &mut CorrelatorContext) -> Result<AutoSpectra, ScratchpadError> {
+    let auto_baselines: Vec<usize> = context
+        .metafits_context
+        .baselines
+        .iter()
+        .enumerate()
+        .filter(|(_, baseline)| baseline.ant1_index == baseline.ant2_index)
+        .map(|(baseline_index, _)| baseline_index)
+        .collect();
+    let plan = VisPlan::new(
+        context,
+        VisSelection {
+            baselines: auto_baselines,
+            ..Default::default()
+        },
+        VisCorrections::default(),
+        VisFlagging::default(),
+    )?;
+    let num_timesteps = context.num_timesteps;
+    let num_fine_chans_per_coarse = context.metafits_context.num_corr_fine_chans_per_coarse;
+    let num_fine_chans = context.num_coarse_chans * num_fine_chans_per_coarse;
+    let fine_chan_freqs_hz = all_fine_chan_freqs_hz(context);
+
+    let mut spectra = vec![
+        [vec![0_f64; num_fine_chans], vec![0_f64; num_fine_chans]];
+        context.metafits_context.num_ants
+    ];
+    for record in plan.records(context) {
+        let record = record?;
+        let fine_chan_index = record.coarse_chan * num_fine_chans_per_coarse + record.fine_chan;
+        // XX and YY are the first and last pols
+        for (pol_spectrum, re_im) in spectra[record.ant1]
+            .iter_mut()
+            .zip([record.vis[0], record.vis[3]].iter())
+        {
+            pol_spectrum[fine_chan_index] +=
+                VisValue::Amplitude.of([re_im.re, re_im.im]) as f64 / num_timesteps as f64;
+        }
+    }
+
+    Ok(AutoSpectra {
+        fine_chan_freqs_hz,
+        spectra,
+    })
+}
+
+/// Draw a grid of panels, one per tile, each with its XX and YY spectrum.
+fn draw_autos<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    autos: &AutoSpectra,
+    tile_names: &[String],
+    num_cols: usize,
+    caption: &str,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let freqs_mhz: Vec<f64> = autos
+        .fine_chan_freqs_hz
+        .iter()
+        .map(|freq_hz| freq_hz / 1e6)
+        .collect();
+    let freq_range = freq_range_mhz(&freqs_mhz, 0.);
+    let num_rows = tile_names.len().div_ceil(num_cols);
+
+    root.fill(&WHITE)?;
+    let root = root.titled(caption, ("sans-serif", 30))?;
+    for ((panel, spectrum), tile_name) in root
+        .split_evenly((num_rows, num_cols))
+        .iter()
+        .zip(autos.spectra.iter())
+        .zip(tile_names)
+    {
+        let max = spectrum
+            .iter()
+            .flatten()
+            .filter(|amplitude| amplitude.is_finite())
+            .fold(0_f64, |max, &amplitude| max.max(amplitude));
+        let mut chart = ChartBuilder::on(panel)
+            .caption(tile_name, ("sans-serif", 14))
+            .margin(5)
+            .x_label_area_size(20)
+            .y_label_area_size(40)
+            .build_cartesian_2d(freq_range.clone(), 0_f64..max.max(f64::MIN_POSITIVE))?;
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .label_style(("sans-serif", 10))
+            .draw()?;
+        for (pol_spectrum, colour) in spectrum.iter().zip([BLUE, RED].iter()) {
+            chart.draw_series(LineSeries::new(
+                freqs_mhz.iter().copied().zip(pol_spectrum.iter().copied()),
+                colour,
+            ))?;
+        }
+    }
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-plot-waterfall", author)]
+pub struct WaterfallOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Baseline index, as in the baselines subcommand
+    #[structopt(short, long)]
+    pub baseline: usize,
+
+    /// Visibility pol to plot
+    #[structopt(short, long, default_value = "xx", possible_values = &["xx", "xy", "yx", "yy"])]
+    pub pol: VisPol,
+
+    /// Part of each visibility to plot
+    #[structopt(long, default_value = "amplitude", possible_values = &["amplitude", "phase"])]
+    pub value: VisValue,
+
+    /// Output image, PNG or SVG depending on the extension
+    #[structopt(short, long, parse(from_os_str))]
+    pub output: std::path::PathBuf,
+
+    /// TrueType font for labels, instead of the built in DejaVu Sans
+    #[structopt(long, parse(from_os_str))]
+    pub font: Option<std::path::PathBuf>,
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-plot-autos", author)]
+pub struct AutosOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Number of tiles in each row of the grid
+    #[structopt(long, default_value = "12")]
+    pub cols: usize,
+
+    /// Output image, PNG or SVG depending on the extension
+    #[structopt(short, long, parse(from_os_str))]
+    pub output: std::path::PathBuf,
+
+    /// TrueType font for labels, instead of the built in DejaVu Sans
+    #[structopt(long, parse(from_os_str))]
+    pub font: Option<std::path::PathBuf>,
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+pub enum PlotOpt {
+    Autos(AutosOpt),
+    Waterfall(WaterfallOpt),
+}
+
+/// Flatten a plotters error, whose type depends on the backend.
+fn plot_error<E: std::error::Error + Send + Sync>(err: DrawingAreaErrorKind<E>) -> ScratchpadError {
+    ScratchpadError::Plot(err.to_string())
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn plot_waterfall(opts: &WaterfallOpt) -> Result<(), ScratchpadError> {
+    load_font(opts.font.as_ref())?;
+    let mut context = CorrelatorContext::new(&opts.metafits, &opts.files)?;
+    let grid = read_baseline_grid(&mut context, opts.baseline, opts.pol)?;
+    if grid.fine_chan_freqs_hz.is_empty() {
+        return Err(ScratchpadError::NoCoarseChannels);
+    }
+    let baseline = &context.metafits_context.baselines[opts.baseline];
+    let caption = format!(
+        "{} baseline {} ({} - {}) {:?}",
+        context.metafits_context.obs_id,
+        opts.baseline,
+        context.metafits_context.antennas[baseline.ant1_index].tile_name,
+        context.metafits_context.antennas[baseline.ant2_index].tile_name,
+        opts.value
+    );
+    match opts
+        .output
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("svg") => draw_waterfall(
+            SVGBackend::new(&opts.output, PLOT_SIZE).into_drawing_area(),
+            &grid,
+            opts.value,
+            &caption,
+        )
+        .map_err(plot_error),
+        _ => draw_waterfall(
+            BitMapBackend::new(&opts.output, PLOT_SIZE).into_drawing_area(),
+            &grid,
+            opts.value,
+            &caption,
+        )
+        .map_err(plot_error),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn plot_autos(opts: &AutosOpt) -> Result<(), ScratchpadError> {
+    load_font(opts.font.as_ref())?;
+    let mut context = CorrelatorContext::new(&opts.metafits, &opts.files)?;
+    let autos = read_auto_spectra(&mut context)?;
+    if autos.fine_chan_freqs_hz.is_empty() {
+        return Err(ScratchpadError::NoCoarseChannels);
+    }
+    let tile_names: Vec<String> = context
+        .metafits_context
+        .antennas
+        .iter()
+        .map(|antenna| {
+            if antenna.rfinput_x.flagged || antenna.rfinput_y.flagged {
+                format!("{} (flagged)", antenna.tile_name)
+            } else {
+                antenna.tile_name.clone()
+            }
+        })
+        .collect();
+    let num_cols = opts.cols.max(1);
+    let num_rows = tile_names.len().div_ceil(num_cols);
+    let size = (
+        AUTOS_PANEL_SIZE.0 * num_cols as u32,
+        AUTOS_PANEL_SIZE.1 * num_rows as u32 + 40,
+    );
+    let caption = format!(
+        "{} autocorrelations (XX blue, YY red)",
+        context.metafits_context.obs_id
+    );
+    match opts
+        .output
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("svg") => draw_autos(
+            SVGBackend::new(&opts.output, size).into_drawing_area(),
+            &autos,
+            &tile_names,
+            num_cols,
+            &caption,
+        )
+        .map_err(plot_error),
+        _ => draw_autos(
+            BitMapBackend::new(&opts.output, size).into_drawing_area(),
+            &autos,
+            &tile_names,
+            num_cols,
+            &caption,
+        )
+        .map_err(plot_error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num_complex::Complex;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_load_default_font() {
+        load_font::<std::path::PathBuf>(None).unwrap();
+    }
+
+    #[test]
+    fn test_vis_value_of() {
+        assert_eq!(VisValue::Amplitude.of([3., -4.]), 5.);
+        assert_eq!(VisValue::Phase.of([0., 2.]), PI / 2.);
+        assert_eq!(VisValue::Phase.of([-1., 0.]), PI);
+        assert_eq!(VisValue::Phase.of([1., 0.]), 0.);
+        assert!(matches!("phase".parse::<VisValue>(), Ok(VisValue::Phase)));
+        assert!("power".parse::<VisValue>().is_err());
+        assert!(matches!("yx".parse::<VisPol>(), Ok(VisPol(2))));
+        assert!("rr".parse::<VisPol>().is_err());
+    }
+
+    fn test_record(coarse_chan: usize, timestep: usize, fine_chan: usize) -> VisRecord {
+        let value = (timestep * 100 + coarse_chan * 10 + fine_chan) as f32;
+        VisRecord {
+            coarse_chan,
+            timestep,
+            baseline: 1,
+            ant1: 0,
+            ant2: 1,
+            ant1_name: Arc::from("Tile011"),
+            ant2_name: Arc::from("Tile012"),
+            fine_chan,
+            freq_hz: 0.,
+            gps_time_ms: 0,
+            unix_time_ms: 0,
+            vis: [
+                Complex::new(value, 0.),
+                Complex::new(value, 1.),
+                Complex::new(value, 2.),
+                Complex::new(value, 3.),
+            ],
+            flagged: false,
+        }
+    }
+
+    #[test]
+    fn test_baseline_grid_from_records() {
+        // 2 coarse channels of 2 fine channels, and 3 timesteps
+        let records: Vec<Result<VisRecord, ScratchpadError>> = (0..2)
+            .flat_map(|coarse_chan| {
+                (0..3).flat_map(move |timestep| {
+                    (0..2).map(move |fine_chan| Ok(test_record(coarse_chan, timestep, fine_chan)))
+                })
+            })
+            .collect();
+        let grid = BaselineGrid::from_records(
+            records.into_iter(),
+            VisPol(3),
+            3,
+            2,
+            vec![139.0e6, 139.04e6, 140.0e6, 140.04e6],
+            40e3,
+        )
+        .unwrap();
+        assert_eq!(grid.num_timesteps, 3);
+        assert_eq!(grid.vis.len(), 3 * 4);
+        for timestep in 0..3 {
+            for coarse_chan in 0..2 {
+                for fine_chan in 0..2 {
+                    let value = (timestep * 100 + coarse_chan * 10 + fine_chan) as f32;
+                    assert_eq!(
+                        grid.vis[timestep * 4 + coarse_chan * 2 + fine_chan],
+                        [value, 3.]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_baseline_grid_from_records_error() {
+        let records = vec![
+            Ok(test_record(0, 0, 0)),
+            Err(ScratchpadError::NoCoarseChannels),
+        ];
+        assert!(BaselineGrid::from_records(
+            records.into_iter(),
+            VisPol(0),
+            1,
+            1,
+            vec![139.0e6],
+            40e3
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_draw_autos_svg() {
+        load_font::<PathBuf>(None).unwrap();
+        // Coarse channels out of frequency order, and a grid with a gap
+        let autos = AutoSpectra {
+            fine_chan_freqs_hz: vec![170.0e6, 170.04e6, 169.0e6, 169.04e6],
+            spectra: vec![[vec![1., 2., 3., 4.], vec![4., 3., 2., 1.]]; 3],
+        };
+        let tile_names = vec![
+            "Tile011".to_string(),
+            "Tile012 (flagged)".to_string(),
+            "Tile013".to_string(),
+        ];
+        let mut svg = String::new();
+        draw_autos(
+            SVGBackend::with_string(&mut svg, (500, 400)).into_drawing_area(),
+            &autos,
+            &tile_names,
+            2,
+            "autos",
+        )
+        .unwrap();
+        for tile_name in &tile_names {
+            assert!(svg.contains(tile_name.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_freq_range_mhz() {
+        // Coarse channels needn't be in ascending frequency
+        assert_eq!(
+            freq_range_mhz(&[170.0, 170.04, 169.0, 169.04], 0.02),
+            168.98..170.06
+        );
+        assert_eq!(freq_range_mhz(&[139.0], 0.), 139.0..139.0);
+    }
+}
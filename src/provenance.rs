@@ -0,0 +1,224 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Record what produced a dump: the software, how it was run, and exactly
+/// which inputs it read.
+use crate::ScratchpadError;
+use chrono::{SecondsFormat, Utc};
+use log::info;
+use mwalib::CorrelatorVersion;
+use rayon::prelude::*;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::ffi::OsString;
+use std::fs::{metadata, File};
+use std::io::Write;
+use std::path::Path;
+
+/// An input file, as it was when it was read.
+#[derive(Serialize, Debug, Clone)]
+pub struct InputFile {
+    pub path: String,
+    pub size_bytes: u64,
+    /// None when checksums were turned off.
+    pub sha256: Option<String>,
+}
+
+impl InputFile {
+    /// Record a file's size, and its checksum if `checksum`.
+    pub fn new<T: AsRef<Path>>(path: &T, checksum: bool) -> Result<Self, ScratchpadError> {
+        let (size_bytes, sha256) = if checksum {
+            let mut file = File::open(path)?;
+            let mut hasher = Sha256::new();
+            let size_bytes = std::io::copy(&mut file, &mut hasher)?;
+            (size_bytes, Some(format!("{:x}", hasher.finalize())))
+        } else {
+            (metadata(path)?.len(), None)
+        };
+        Ok(InputFile {
+            path: path.as_ref().display().to_string(),
+            size_bytes,
+            sha256,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Provenance {
+    pub software: String,
+    pub version: String,
+    /// From `git describe`, ending in `-dirty` if there were uncommitted changes.
+    pub git_hash: String,
+    pub command_line: Vec<String>,
+    pub inputs: Vec<InputFile>,
+    pub mwalib_version: String,
+    pub corr_version: String,
+    /// When the dump was started, in RFC 3339.
+    pub created_utc: String,
+}
+
+impl Provenance {
+    /// The provenance of a dump being made now by `command_line`, after any
+    /// `--config` or `--obs-dir` expansion, from `inputs`, which are
+    /// checksummed in parallel if `checksums`.
+    pub fn new<T: AsRef<Path> + Sync>(
+        command_line: &[OsString],
+        inputs: &[T],
+        corr_version: CorrelatorVersion,
+        checksums: bool,
+    ) -> Result<Self, ScratchpadError> {
+        if checksums {
+            info!("Checksumming {} input files", inputs.len());
+        }
+        Ok(Provenance {
+            software: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_hash: env!("GIT_HASH").to_string(),
+            command_line: command_line
+                .iter()
+                .map(|arg| arg.to_string_lossy().to_string())
+                .collect(),
+            inputs: inputs
+                .par_iter()
+                .map(|input| InputFile::new(input, checksums))
+                .collect::<Result<_, _>>()?,
+            mwalib_version: env!("MWALIB_VERSION").to_string(),
+            corr_version: corr_version.to_string(),
+            created_utc: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        })
+    }
+
+    /// The provenance as `key: value` lines, with a line per input.
+    pub fn lines(&self) -> Vec<String> {
+        let command_line: Vec<String> = self
+            .command_line
+            .iter()
+            .map(|arg| {
+                if arg.contains(char::is_whitespace) {
+                    format!("'{}'", arg)
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect();
+        let mut lines = vec![
+            format!("software: {} {}", self.software, self.version),
+            format!("git_hash: {}", self.git_hash),
+            format!("command_line: {}", command_line.join(" ")),
+            format!("mwalib_version: {}", self.mwalib_version),
+            format!("corr_version: {}", self.corr_version),
+            format!("created_utc: {}", self.created_utc),
+        ];
+        for input in self.inputs.iter() {
+            lines.push(match &input.sha256 {
+                Some(sha256) => format!(
+                    "input: {} {} bytes sha256 {}",
+                    input.path, input.size_bytes, sha256
+                ),
+                None => format!("input: {} {} bytes", input.path, input.size_bytes),
+            });
+        }
+        lines
+    }
+
+    /// Write the provenance as `# ` comment lines, e.g. before a CSV header.
+    pub fn write_comments<W: Write>(&self, writer: &mut W) -> Result<(), ScratchpadError> {
+        for line in self.lines() {
+            writeln!(writer, "# {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_file_checksums() {
+        let path = std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-provenance-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "abc").unwrap();
+        let checksummed = InputFile::new(&path, true).unwrap();
+        let unchecksummed = InputFile::new(&path, false).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(checksummed.size_bytes, 3);
+        assert_eq!(
+            checksummed.sha256.as_deref(),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(unchecksummed.size_bytes, 3);
+        assert_eq!(unchecksummed.sha256, None);
+    }
+
+    #[test]
+    fn test_provenance_new() {
+        let path = std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-provenance-new-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "abc").unwrap();
+        let command_line = [
+            OsString::from("mwa-scratchpad"),
+            OsString::from("dump-all-data"),
+        ];
+        let provenance = Provenance::new(&command_line, &[&path], CorrelatorVersion::V2, false);
+        std::fs::remove_file(&path).unwrap();
+        let provenance = provenance.unwrap();
+        assert_eq!(provenance.command_line, ["mwa-scratchpad", "dump-all-data"]);
+        assert_eq!(provenance.inputs.len(), 1);
+        assert_eq!(provenance.inputs[0].path, path.display().to_string());
+        assert_eq!(provenance.corr_version, CorrelatorVersion::V2.to_string());
+    }
+
+    #[test]
+    fn test_provenance_lines() {
+        let provenance = Provenance {
+            software: "mwa_scratchpad".to_string(),
+            version: "0.1.0".to_string(),
+            git_hash: "abc1234-dirty".to_string(),
+            command_line: vec![
+                "mwa-scratchpad".to_string(),
+                "dump-all-data".to_string(),
+                "--metafits".to_string(),
+                "obs dir/1065880128.metafits".to_string(),
+            ],
+            inputs: vec![
+                InputFile {
+                    path: "1065880128.metafits".to_string(),
+                    size_bytes: 3,
+                    sha256: Some("ba7816bf".to_string()),
+                },
+                InputFile {
+                    path: "1065880128_gpubox01_00.fits".to_string(),
+                    size_bytes: 5,
+                    sha256: None,
+                },
+            ],
+            mwalib_version: "0.6.3".to_string(),
+            corr_version: "V2".to_string(),
+            created_utc: "2021-02-05T12:00:00Z".to_string(),
+        };
+        assert_eq!(
+            provenance.lines(),
+            [
+                "software: mwa_scratchpad 0.1.0",
+                "git_hash: abc1234-dirty",
+                "command_line: mwa-scratchpad dump-all-data --metafits 'obs dir/1065880128.metafits'",
+                "mwalib_version: 0.6.3",
+                "corr_version: V2",
+                "created_utc: 2021-02-05T12:00:00Z",
+                "input: 1065880128.metafits 3 bytes sha256 ba7816bf",
+                "input: 1065880128_gpubox01_00.fits 5 bytes",
+            ]
+        );
+        let mut comments = Vec::new();
+        provenance.write_comments(&mut comments).unwrap();
+        let comments = String::from_utf8(comments).unwrap();
+        assert!(comments.starts_with("# software: mwa_scratchpad 0.1.0\n# git_hash: "));
+        assert_eq!(comments.lines().count(), 8);
+    }
+}
@@ -0,0 +1,148 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given a metafits file, tabulate every rf input for cable-length and
+/// array-layout audits.
+use crate::serialize::serialize_rf_inputs;
+use crate::ScratchpadError;
+use mwalib::{MetafitsContext, RFInput};
+use std::io::Write;
+use std::str::FromStr;
+use structopt::StructOpt;
+
+/// Which `RFInput` field to sort rows by.
+#[derive(Debug, Clone, Copy)]
+pub enum RFInputOrder {
+    Input,
+    VcsOrder,
+    SubfileOrder,
+}
+
+impl FromStr for RFInputOrder {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "input" => Ok(RFInputOrder::Input),
+            "vcs_order" => Ok(RFInputOrder::VcsOrder),
+            "subfile_order" => Ok(RFInputOrder::SubfileOrder),
+            _ => Err(ScratchpadError::UnknownChoice {
+                kind: "rf input order",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-rf-inputs", author)]
+pub struct RFInputsOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Field to sort rf inputs by
+    #[structopt(
+        short,
+        long,
+        default_value = "input",
+        possible_values = &["input", "vcs_order", "subfile_order"]
+    )]
+    pub sort_by: RFInputOrder,
+
+    /// Print JSON instead of CSV
+    #[structopt(short, long)]
+    pub json: bool,
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn dump_rf_inputs<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    sort_by: RFInputOrder,
+    json: bool,
+) -> Result<(), ScratchpadError> {
+    let context = MetafitsContext::new(metafits)?;
+    let mut rf_inputs: Vec<RFInput> = context.rf_inputs;
+    match sort_by {
+        RFInputOrder::Input => rf_inputs.sort_by_key(|rf_input| rf_input.input),
+        RFInputOrder::VcsOrder => rf_inputs.sort_by_key(|rf_input| rf_input.vcs_order),
+        RFInputOrder::SubfileOrder => rf_inputs.sort_by_key(|rf_input| rf_input.subfile_order),
+    }
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+
+    if json {
+        writeln!(
+            &mut out,
+            "{}",
+            serde_json::to_string_pretty(&serialize_rf_inputs(rf_inputs))?
+        )?;
+        return Ok(());
+    }
+
+    writeln!(
+        &mut out,
+        "input,ant,tile_id,tile_name,pol,electrical_length_m,north_m,east_m,height_m,vcs_order,subfile_order,flagged,digital_gains,dipole_gains,dipole_delays,rec_number,rec_slot_number"
+    )?;
+    for rf_input in &rf_inputs {
+        writeln!(
+            &mut out,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            rf_input.input,
+            rf_input.ant,
+            rf_input.tile_id,
+            rf_input.tile_name,
+            rf_input.pol,
+            rf_input.electrical_length_m,
+            rf_input.north_m,
+            rf_input.east_m,
+            rf_input.height_m,
+            rf_input.vcs_order,
+            rf_input.subfile_order,
+            rf_input.flagged,
+            join(&rf_input.digital_gains),
+            join(&rf_input.dipole_gains),
+            join(&rf_input.dipole_delays),
+            rf_input.rec_number,
+            rf_input.rec_slot_number,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rf_input_order_from_str() {
+        assert!(matches!(
+            "vcs_order".parse::<RFInputOrder>(),
+            Ok(RFInputOrder::VcsOrder)
+        ));
+        assert!(matches!(
+            "subfile_order".parse::<RFInputOrder>(),
+            Ok(RFInputOrder::SubfileOrder)
+        ));
+        assert!("antenna".parse::<RFInputOrder>().is_err());
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(join(&[64_u32, 65, 66]), "64;65;66");
+        assert_eq!(join(&[1.5_f64]), "1.5");
+        assert_eq!(join::<u32>(&[]), "");
+    }
+}
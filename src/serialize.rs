@@ -1,124 +1,130 @@
-// use chrono::{DateTime, FixedOffset};
+use crate::gpubox::TimeMapCoverage;
+use chrono::{DateTime, FixedOffset};
 use mwalib::{
-    // antenna::Antenna,
-    // baseline::Baseline,
-    coarse_channel::CoarseChannel,
-    metafits_context::MetafitsContext,
-    // conversion::LegacyConversionBaseline,
-    metafits_context::CorrelatorVersion,
-    // gpubox_files::GPUBoxBatch,
-    // rfinput::RFInput,
-    timestep::TimeStep,
-    // visibility_pol::VisibilityPol,
-    CorrelatorContext,
+    Antenna, Baseline, CoarseChannel, CorrelatorContext, CorrelatorVersion, MetafitsContext,
+    RFInput, TimeStep, VisibilityPol,
 };
 use serde::Serialize;
-use std::collections::BTreeMap;
+
+#[derive(Serialize, Debug)]
+#[serde(remote = "MetafitsContext")]
+pub struct MWALibMetafitsContextDef {
+    pub obs_id: u32,
+    pub sched_start_gps_time_ms: u64,
+    pub sched_end_gps_time_ms: u64,
+    pub sched_start_unix_time_ms: u64,
+    pub sched_end_unix_time_ms: u64,
+    #[serde(with = "date_time_fixed_offset_shim")]
+    pub sched_start_utc: DateTime<FixedOffset>,
+    #[serde(with = "date_time_fixed_offset_shim")]
+    pub sched_end_utc: DateTime<FixedOffset>,
+    pub sched_start_mjd: f64,
+    pub sched_end_mjd: f64,
+    pub sched_duration_ms: u64,
+    pub ra_tile_pointing_degrees: f64,
+    pub dec_tile_pointing_degrees: f64,
+    pub ra_phase_center_degrees: Option<f64>,
+    pub dec_phase_center_degrees: Option<f64>,
+    pub az_deg: f64,
+    pub alt_deg: f64,
+    pub za_deg: f64,
+    #[serde(skip)]
+    pub az_rad: f64,
+    #[serde(skip)]
+    pub alt_rad: f64,
+    #[serde(skip)]
+    pub za_rad: f64,
+    pub sun_alt_deg: f64,
+    pub sun_distance_deg: f64,
+    pub moon_distance_deg: f64,
+    pub jupiter_distance_deg: f64,
+    pub lst_deg: f64,
+    #[serde(skip)]
+    pub lst_rad: f64,
+    pub hour_angle_string: String,
+    pub grid_name: String,
+    pub grid_number: i32,
+    pub creator: String,
+    pub project_id: String,
+    pub obs_name: String,
+    pub mode: String,
+    pub corr_fine_chan_width_hz: u32,
+    pub corr_int_time_ms: u64,
+    pub num_corr_fine_chans_per_coarse: usize,
+    pub receivers: Vec<usize>,
+    pub delays: Vec<u32>,
+    pub global_analogue_attenuation_db: f64,
+    pub quack_time_duration_ms: u64,
+    pub good_time_unix_ms: u64,
+    pub good_time_gps_ms: u64,
+    pub num_ants: usize,
+    #[serde(with = "antennas_shim")]
+    pub antennas: Vec<Antenna>,
+    pub num_rf_inputs: usize,
+    #[serde(with = "rfinputs_shim")]
+    pub rf_inputs: Vec<RFInput>,
+    pub num_ant_pols: usize,
+    pub num_coarse_chans: usize,
+    pub obs_bandwidth_hz: u32,
+    pub coarse_chan_width_hz: u32,
+    pub centre_freq_hz: u32,
+    pub num_baselines: usize,
+    #[serde(with = "baselines_shim")]
+    pub baselines: Vec<Baseline>,
+    pub num_visibility_pols: usize,
+    #[serde(with = "visibility_pols_shim")]
+    pub visibility_pols: Vec<VisibilityPol>,
+    pub metafits_filename: String,
+}
 
 #[derive(Serialize, Debug)]
 #[serde(remote = "CorrelatorContext")]
 pub struct MWALibContextDef {
-    // pub mwa_latitude_radians: f64,
-    // pub mwa_longitude_radians: f64,
-    // pub mwa_altitude_metres: f64,
-    // pub coax_v_factor: f64,
-    // pub obsid: u32,
-    // pub scheduled_start_gpstime_milliseconds: u64,
-    // pub scheduled_end_gpstime_milliseconds: u64,
-    // pub scheduled_start_unix_time_milliseconds: u64,
-    // pub scheduled_end_unix_time_milliseconds: u64,
-    // #[serde(with = "date_time_fixed_offset_shim")]
-    // pub scheduled_start_utc: DateTime<FixedOffset>,
-    // #[serde(with = "date_time_fixed_offset_shim")]
-    // pub scheduled_end_utc: DateTime<FixedOffset>,
-    // pub scheduled_start_mjd: f64,
-    // pub scheduled_end_mjd: f64,
-    // pub scheduled_duration_milliseconds: u64,
-    // pub ra_tile_pointing_degrees: f64,
-    // pub dec_tile_pointing_degrees: f64,
-    // pub ra_phase_center_degrees: Option<f64>,
-    // pub dec_phase_center_degrees: Option<f64>,
-    // pub azimuth_degrees: f64,
-    // pub altitude_degrees: f64,
-    // pub sun_altitude_degrees: f64,
-    // pub sun_distance_degrees: f64,
-    // pub moon_distance_degrees: f64,
-    // pub jupiter_distance_degrees: f64,
-    // pub lst_degrees: f64,
-    // pub hour_angle_string: String,
-    // pub grid_name: String,
-    // pub grid_number: i32,
-    // pub creator: String,
-    // pub project_id: String,
-    // pub observation_name: String,
-    // pub mode: String,
-    // pub receivers: Vec<usize>,
-    // pub dipole_delays: Vec<usize>,
-    // pub global_analogue_attenuation_db: f64,
-    // pub quack_time_duration_milliseconds: u64,
-    // pub good_time_unix_milliseconds: u64,
+    #[serde(with = "MWALibMetafitsContextDef")]
+    pub metafits_context: MetafitsContext,
     #[serde(with = "correlator_version_shim")]
     pub corr_version: CorrelatorVersion,
-    #[serde(skip)]
-    pub metafits_context: MetafitsContext,
-    pub start_unix_time_milliseconds: u64,
-    pub end_unix_time_milliseconds: u64,
-    pub duration_milliseconds: u64,
+    pub start_unix_time_ms: u64,
+    pub end_unix_time_ms: u64,
+    pub start_gps_time_ms: u64,
+    pub end_gps_time_ms: u64,
+    pub duration_ms: u64,
     pub num_timesteps: usize,
     #[serde(with = "time_steps_shim")]
     pub timesteps: Vec<TimeStep>,
-    // pub num_antennas: usize,
-    // #[serde(with = "antennas_shim")]
-    // pub antennas: Vec<Antenna>,
-    // pub num_baselines: usize,
-    // #[serde(with = "baselines_shim")]
-    // pub baselines: Vec<Baseline>,
-    // pub num_rf_inputs: usize,
-    // #[serde(with = "rfinputs_shim")]
-    // pub rf_inputs: Vec<RFInput>,
-    // pub num_antenna_pols: usize,
-    // pub num_visibility_pols: usize,
-    // #[serde(with = "visibility_pols_shim")]
-    // pub visibility_pols: Vec<VisibilityPol>,
-    // pub correlator_integration_time_milliseconds: u64,
-    pub num_coarse_channels: usize,
+    pub num_coarse_chans: usize,
     #[serde(with = "coarse_channels_shim")]
-    pub coarse_channels: Vec<CoarseChannel>,
+    pub coarse_chans: Vec<CoarseChannel>,
     pub bandwidth_hz: u32,
-    // pub coarse_channel_width_hz: u32,
-    // pub correlator_fine_channel_width_hz: u32,
-    // pub metafits_centre_freq_hz: u32,
-    // pub num_fine_channels_per_coarse: usize,
-    // pub metafits_filename: String,
     // #[serde(with = "gpu_box_batch_shim")]
     // pub gpubox_batches: Vec<GPUBoxBatch>,
-    #[serde(skip)]
-    pub gpubox_time_map: BTreeMap<u64, BTreeMap<usize, (usize, usize)>>,
-    pub num_timestep_coarse_channel_bytes: usize,
-    pub num_timestep_coarse_channel_floats: usize,
+    // `gpubox_time_map` is private to mwalib, see `SerializableContext`.
+    // pub gpubox_time_map: BTreeMap<u64, BTreeMap<usize, (usize, usize)>>,
+    pub num_timestep_coarse_chan_bytes: usize,
+    pub num_timestep_coarse_chan_floats: usize,
     pub num_gpubox_files: usize,
     // #[serde(skip)]
     // pub legacy_conversion_table: Vec<LegacyConversionBaseline>,
 }
 
-// mod date_time_fixed_offset_shim {
-//     use chrono::{DateTime, FixedOffset};
-//     use serde::Serializer;
+mod date_time_fixed_offset_shim {
+    use chrono::{DateTime, FixedOffset};
+    use serde::Serializer;
 
-//     pub fn to_serializable(v: &DateTime<FixedOffset>) -> String {
-//         v.to_rfc3339()
-//     }
+    pub fn to_serializable(v: &DateTime<FixedOffset>) -> String {
+        v.to_rfc3339()
+    }
 
-//     pub fn serialize<S>(v: &DateTime<FixedOffset>, s: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         s.serialize_str(&to_serializable(v))
-//     }
-// }
+    pub fn serialize<S>(v: &DateTime<FixedOffset>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_serializable(v))
+    }
+}
 
 mod correlator_version_shim {
-    use mwalib::metafits_context::CorrelatorVersion;
+    use mwalib::CorrelatorVersion;
     use serde::Serializer;
 
     pub fn to_serializable(v: &CorrelatorVersion) -> &str {
@@ -138,14 +144,14 @@ mod correlator_version_shim {
 }
 
 mod time_steps_shim {
-    use mwalib::timestep::TimeStep;
+    use mwalib::TimeStep;
     use serde::{ser::SerializeSeq, Serializer};
 
-    pub fn to_serializable(v: &Vec<TimeStep>) -> Vec<u64> {
-        v.into_iter().map(|e| e.unix_time_milliseconds).collect()
+    pub fn to_serializable(v: &[TimeStep]) -> Vec<u64> {
+        v.iter().map(|e| e.unix_time_ms).collect()
     }
 
-    pub fn serialize<S>(v: &Vec<TimeStep>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(v: &[TimeStep], s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -157,28 +163,28 @@ mod time_steps_shim {
     }
 }
 
-// mod visibility_pols_shim {
-//     use mwalib::visibility_pol::VisibilityPol;
-//     use serde::{ser::SerializeSeq, Serializer};
+mod visibility_pols_shim {
+    use mwalib::VisibilityPol;
+    use serde::{ser::SerializeSeq, Serializer};
 
-//     pub fn to_serializable(v: &Vec<VisibilityPol>) -> Vec<String> {
-//         v.into_iter().map(|e| e.polarisation.clone()).collect()
-//     }
+    pub fn to_serializable(v: &[VisibilityPol]) -> Vec<String> {
+        v.iter().map(|e| e.polarisation.clone()).collect()
+    }
 
-//     pub fn serialize<S>(v: &Vec<VisibilityPol>, s: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         let mut seq = s.serialize_seq(Some(v.len()))?;
-//         for e in to_serializable(v) {
-//             seq.serialize_element(&e)?;
-//         }
-//         seq.end()
-//     }
-// }
+    pub fn serialize<S>(v: &[VisibilityPol], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = s.serialize_seq(Some(v.len()))?;
+        for e in to_serializable(v) {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
 
 mod pol_shim {
-    use mwalib::rfinput::Pol;
+    use mwalib::Pol;
     use serde::Serializer;
 
     pub fn to_serializable(v: &Pol) -> &str {
@@ -192,20 +198,20 @@ mod pol_shim {
     where
         S: Serializer,
     {
-        s.serialize_str(&to_serializable(v))
+        s.serialize_str(to_serializable(v))
     }
 }
 
 mod rfinput_shim {
     use super::pol_shim;
-    use mwalib::rfinput::{Pol, RFInput};
+    use mwalib::{Pol, RFInput};
     use serde::{ser::SerializeStruct, Serialize, Serializer};
 
-    #[serde(remote = "RFInput")]
     #[derive(Serialize, Debug, Clone)]
+    #[serde(remote = "RFInput")]
     pub struct MWALibRFInputDef {
         pub input: u32,
-        pub antenna: u32,
+        pub ant: u32,
         pub tile_id: u32,
         pub tile_name: String,
         #[serde(with = "pol_shim")]
@@ -218,9 +224,10 @@ mod rfinput_shim {
         pub subfile_order: u32,
         pub flagged: bool,
         pub digital_gains: Vec<u32>,
+        pub dipole_gains: Vec<f64>,
         pub dipole_delays: Vec<u32>,
-        pub receiver_number: u32,
-        pub receiver_slot_number: u32,
+        pub rec_number: u32,
+        pub rec_slot_number: u32,
     }
 
     #[derive(Serialize)]
@@ -230,9 +237,9 @@ mod rfinput_shim {
     where
         S: Serializer,
     {
-        let mut state = s.serialize_struct("MWALibRFInputWrapper", 15)?;
+        let mut state = s.serialize_struct("MWALibRFInputWrapper", 17)?;
         state.serialize_field("input", &v.input)?;
-        state.serialize_field("antenna", &v.antenna)?;
+        state.serialize_field("ant", &v.ant)?;
         state.serialize_field("tile_id", &v.tile_id)?;
         state.serialize_field("tile_name", &v.tile_name)?;
         state.serialize_field("pol", pol_shim::to_serializable(&v.pol))?;
@@ -244,151 +251,152 @@ mod rfinput_shim {
         state.serialize_field("subfile_order", &v.subfile_order)?;
         state.serialize_field("flagged", &v.flagged)?;
         state.serialize_field("digital_gains", &v.digital_gains)?;
+        state.serialize_field("dipole_gains", &v.dipole_gains)?;
         state.serialize_field("dipole_delays", &v.dipole_delays)?;
-        state.serialize_field("receiver_number", &v.receiver_number)?;
-        state.serialize_field("receiver_slot_number", &v.receiver_slot_number)?;
+        state.serialize_field("rec_number", &v.rec_number)?;
+        state.serialize_field("rec_slot_number", &v.rec_slot_number)?;
         state.end()
     }
 }
 
-// mod rfinputs_shim {
-//     use super::rfinput_shim;
-//     use mwalib::rfinput::RFInput;
-//     use serde::{ser::SerializeSeq, Serializer};
-
-//     pub fn serialize<S>(v: &Vec<RFInput>, s: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         let mut seq = s.serialize_seq(Some(v.len()))?;
-//         for e in v {
-//             seq.serialize_element(&rfinput_shim::MWALibRFInputWrapper(e.clone()))?;
-//         }
-//         seq.end()
-//     }
-// }
-
-// mod antennas_shim {
-//     use super::rfinput_shim;
-//     use mwalib::{antenna::Antenna, rfinput::RFInput};
-//     use serde::{ser::SerializeSeq, Serialize, Serializer};
-
-//     #[serde(remote = "Antenna")]
-//     #[derive(Serialize, Debug)]
-//     pub struct MWALibAntennaDef {
-//         pub antenna: u32,
-//         pub tile_id: u32,
-//         pub tile_name: String,
-//         #[serde(with = "rfinput_shim")]
-//         pub x_pol: RFInput,
-//         #[serde(with = "rfinput_shim")]
-//         pub y_pol: RFInput,
-//     }
-
-//     #[derive(Serialize)]
-//     pub struct MWALibAntennaWrapper(#[serde(with = "MWALibAntennaDef")] Antenna);
-
-//     pub fn serialize<S>(v: &Vec<Antenna>, s: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         let mut seq = s.serialize_seq(Some(v.len()))?;
-//         for e in v {
-//             seq.serialize_element(&MWALibAntennaWrapper(e.clone()))?;
-//         }
-//         seq.end()
-//     }
-// }
-
-// mod baselines_shim {
-//     use mwalib::baseline::Baseline;
-//     use serde::{ser::SerializeSeq, Serialize, Serializer};
-
-//     // #[serde(remote = "Baseline")]
-//     #[derive(Serialize, Debug)]
-//     pub struct MWALibBaselineDef(usize, usize);
+mod rfinputs_shim {
+    use super::rfinput_shim;
+    use mwalib::RFInput;
+    use serde::{ser::SerializeSeq, Serializer};
 
-//     pub fn serialize<S>(v: &Vec<Baseline>, s: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         let mut seq = s.serialize_seq(Some(v.len()))?;
-//         for e in v {
-//             seq.serialize_element(&MWALibBaselineDef(e.antenna1_index, e.antenna2_index))?;
-//         }
-//         seq.end()
-//     }
-// }
+    pub fn serialize<S>(v: &[RFInput], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = s.serialize_seq(Some(v.len()))?;
+        for e in v {
+            seq.serialize_element(&rfinput_shim::MWALibRFInputWrapper(e.clone()))?;
+        }
+        seq.end()
+    }
+}
 
-mod coarse_channels_shim {
-    use mwalib::coarse_channel::CoarseChannel;
+mod antennas_shim {
+    use super::rfinput_shim;
+    use mwalib::{Antenna, RFInput};
     use serde::{ser::SerializeSeq, Serialize, Serializer};
 
-    #[serde(remote = "CoarseChannel")]
     #[derive(Serialize, Debug)]
-    pub struct MWALibCoarseChannelDef {
-        pub correlator_channel_number: usize,
-        pub receiver_channel_number: usize,
-        pub gpubox_number: usize,
-        pub channel_width_hz: u32,
-        pub channel_start_hz: u32,
-        pub channel_centre_hz: u32,
-        pub channel_end_hz: u32,
+    #[serde(remote = "Antenna")]
+    pub struct MWALibAntennaDef {
+        pub ant: u32,
+        pub tile_id: u32,
+        pub tile_name: String,
+        #[serde(with = "rfinput_shim")]
+        pub rfinput_x: RFInput,
+        #[serde(with = "rfinput_shim")]
+        pub rfinput_y: RFInput,
     }
 
     #[derive(Serialize)]
-    pub struct MWALibCoarseChannelWrapper(#[serde(with = "MWALibCoarseChannelDef")] CoarseChannel);
+    pub struct MWALibAntennaWrapper(#[serde(with = "MWALibAntennaDef")] Antenna);
 
-    pub fn serialize<S>(v: &Vec<CoarseChannel>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(v: &[Antenna], s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let mut seq = s.serialize_seq(Some(v.len()))?;
         for e in v {
-            seq.serialize_element(&MWALibCoarseChannelWrapper(e.clone()))?;
+            seq.serialize_element(&MWALibAntennaWrapper(e.clone()))?;
         }
         seq.end()
     }
 }
 
-mod gpu_box_file_shim {
-    use mwalib::gpubox_files::GPUBoxFile;
+mod baselines_shim {
+    use mwalib::Baseline;
     use serde::{ser::SerializeSeq, Serialize, Serializer};
 
-    #[serde(remote = "GPUBoxFile")]
+    // #[serde(remote = "Baseline")]
     #[derive(Serialize, Debug)]
-    pub struct GPUBoxFileDef {
-        pub filename: String,
-        pub channel_identifier: usize,
-    }
+    pub struct MWALibBaselineDef(usize, usize);
 
-    impl From<GPUBoxFile> for GPUBoxFileDef {
-        fn from(file: GPUBoxFile) -> Self {
-            GPUBoxFileDef {
-                filename: file.filename.clone(),
-                channel_identifier: file.channel_identifier,
-            }
+    pub fn serialize<S>(v: &[Baseline], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = s.serialize_seq(Some(v.len()))?;
+        for e in v {
+            seq.serialize_element(&MWALibBaselineDef(e.ant1_index, e.ant2_index))?;
         }
+        seq.end()
+    }
+}
+
+mod coarse_channels_shim {
+    use mwalib::CoarseChannel;
+    use serde::{ser::SerializeSeq, Serialize, Serializer};
+
+    #[derive(Serialize, Debug)]
+    #[serde(remote = "CoarseChannel")]
+    pub struct MWALibCoarseChannelDef {
+        pub corr_chan_number: usize,
+        pub rec_chan_number: usize,
+        pub gpubox_number: usize,
+        pub chan_width_hz: u32,
+        pub chan_start_hz: u32,
+        pub chan_centre_hz: u32,
+        pub chan_end_hz: u32,
     }
 
     #[derive(Serialize)]
-    pub struct GPUBoxBatchWrapper(#[serde(with = "GPUBoxFileDef")] GPUBoxFile);
+    pub struct MWALibCoarseChannelWrapper(#[serde(with = "MWALibCoarseChannelDef")] CoarseChannel);
 
-    pub fn serialize<S>(v: &Vec<GPUBoxFile>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(v: &Vec<CoarseChannel>, s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let mut seq = s.serialize_seq(Some(v.len()))?;
         for e in v {
-            seq.serialize_element(&GPUBoxBatchWrapper(GPUBoxFile {
-                filename: e.filename.clone(),
-                channel_identifier: e.channel_identifier,
-            }))?;
+            seq.serialize_element(&MWALibCoarseChannelWrapper(e.clone()))?;
         }
         seq.end()
     }
 }
 
+// mod gpu_box_file_shim {
+//     use mwalib::gpubox_files::GPUBoxFile;
+//     use serde::{ser::SerializeSeq, Serialize, Serializer};
+
+//     #[serde(remote = "GPUBoxFile")]
+//     #[derive(Serialize, Debug)]
+//     pub struct GPUBoxFileDef {
+//         pub filename: String,
+//         pub channel_identifier: usize,
+//     }
+
+//     impl From<GPUBoxFile> for GPUBoxFileDef {
+//         fn from(file: GPUBoxFile) -> Self {
+//             GPUBoxFileDef {
+//                 filename: file.filename.clone(),
+//                 channel_identifier: file.channel_identifier,
+//             }
+//         }
+//     }
+
+//     #[derive(Serialize)]
+//     pub struct GPUBoxBatchWrapper(#[serde(with = "GPUBoxFileDef")] GPUBoxFile);
+
+//     pub fn serialize<S>(v: &Vec<GPUBoxFile>, s: S) -> Result<S::Ok, S::Error>
+//     where
+//         S: Serializer,
+//     {
+//         let mut seq = s.serialize_seq(Some(v.len()))?;
+//         for e in v {
+//             seq.serialize_element(&GPUBoxBatchWrapper(GPUBoxFile {
+//                 filename: e.filename.clone(),
+//                 channel_identifier: e.channel_identifier,
+//             }))?;
+//         }
+//         seq.end()
+//     }
+// }
+
 // pub mod gpu_box_batch_shim {
 //     use super::gpu_box_file_shim;
 //     use mwalib::gpubox_files::{GPUBoxBatch, GPUBoxFile};
@@ -432,8 +440,32 @@ mod gpu_box_file_shim {
 // }
 
 #[derive(Serialize)]
-pub struct SerializableContext(#[serde(with = "MWALibContextDef")] CorrelatorContext);
+pub struct SerializableMetafitsContext(#[serde(with = "MWALibMetafitsContextDef")] MetafitsContext);
 
-pub fn serialize_context(context: CorrelatorContext) -> SerializableContext {
-    SerializableContext(context)
+pub fn serialize_metafits_context(context: MetafitsContext) -> SerializableMetafitsContext {
+    SerializableMetafitsContext(context)
+}
+
+#[derive(Serialize)]
+pub struct SerializableRFInputs(#[serde(with = "rfinputs_shim")] Vec<RFInput>);
+
+pub fn serialize_rf_inputs(rf_inputs: Vec<RFInput>) -> SerializableRFInputs {
+    SerializableRFInputs(rf_inputs)
+}
+
+#[derive(Serialize)]
+pub struct SerializableContext {
+    #[serde(flatten, with = "MWALibContextDef")]
+    context: CorrelatorContext,
+    gpubox_time_map: TimeMapCoverage,
+}
+
+pub fn serialize_context(
+    context: CorrelatorContext,
+    gpubox_time_map: TimeMapCoverage,
+) -> SerializableContext {
+    SerializableContext {
+        context,
+        gpubox_time_map,
+    }
 }
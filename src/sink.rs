@@ -0,0 +1,934 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Output formats for dumps. Each format is a `DumpSink`, which is given the
+/// observation's metadata, then blocks of records in order, so the traversal
+/// in `dump_all_data` doesn't need to know how they're written.
+use crate::output::{Compression, DumpWriter};
+use crate::provenance::Provenance;
+use crate::uvw::Uvw;
+use crate::vis::VisRecord;
+use crate::ScratchpadError;
+use arrow_array::{
+    ArrayRef, BooleanArray, Float32Array, Float64Array, RecordBatch, StringArray, UInt32Array,
+    UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use fitsio::errors::check_status;
+use fitsio::hdu::FitsHdu;
+use fitsio::tables::{ColumnDataType, ColumnDescription};
+use fitsio::FitsFile;
+use mwalib::fitsio_sys;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression as ParquetCompression;
+use parquet::file::properties::WriterProperties;
+use radix_fmt::radix;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+const FITS_VIS_COLUMNS: [&str; 8] = [
+    "XX_RE", "XX_IM", "XY_RE", "XY_IM", "YX_RE", "YX_IM", "YY_RE", "YY_IM",
+];
+const FITS_UVW_COLUMNS: [&str; 6] = ["U_M", "V_M", "W_M", "U_LAMBDA", "V_LAMBDA", "W_LAMBDA"];
+const PARQUET_VIS_COLUMNS: [&str; 8] = [
+    "xx_re", "xx_im", "xy_re", "xy_im", "yx_re", "yx_im", "yy_re", "yy_im",
+];
+const PARQUET_UVW_COLUMNS: [&str; 6] = ["u_m", "v_m", "w_m", "u_lambda", "v_lambda", "w_lambda"];
+
+/// Which format to dump in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DumpFormat {
+    Csv,
+    Json,
+    Fits,
+    Parquet,
+}
+
+impl FromStr for DumpFormat {
+    type Err = ScratchpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(DumpFormat::Csv),
+            "json" => Ok(DumpFormat::Json),
+            "fits" => Ok(DumpFormat::Fits),
+            "parquet" => Ok(DumpFormat::Parquet),
+            _ => Err(ScratchpadError::UnknownChoice {
+                kind: "format",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl DumpFormat {
+    /// Guess the format from a filename ending in `.json`, `.fits` or
+    /// `.parquet`, before any compression extension, or CSV otherwise.
+    pub fn from_path<T: AsRef<Path>>(path: &T) -> Self {
+        let path = path.as_ref();
+        let path = match Compression::from_path(&path) {
+            Compression::None => path.to_path_buf(),
+            _ => path.with_extension(""),
+        };
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => DumpFormat::Json,
+            Some("fits") => DumpFormat::Fits,
+            Some("parquet") => DumpFormat::Parquet,
+            _ => DumpFormat::Csv,
+        }
+    }
+}
+
+/// About the observation, and which optional columns each record has.
+#[derive(Serialize, Debug, Clone)]
+pub struct DumpMetadata {
+    pub obs_id: u32,
+    pub corr_version: String,
+    pub num_coarse_chans: usize,
+    pub num_timesteps: usize,
+    pub num_baselines: usize,
+    pub num_fine_chans_per_coarse: usize,
+    pub fine_chan_width_hz: u32,
+    pub int_time_ms: u64,
+    /// Whether blocks carry the UVW of each baseline.
+    pub uvw: bool,
+    /// Whether records are marked flagged or not.
+    pub flagged: bool,
+    pub provenance: Provenance,
+}
+
+/// The records of one timestep of one coarse channel.
+#[derive(Debug, Clone)]
+pub struct RecordBlock {
+    pub records: Vec<VisRecord>,
+    /// The UVW of every baseline in metres, by baseline index, if asked for.
+    pub uvws: Vec<Uvw>,
+}
+
+/// Somewhere to write a dump.
+pub trait DumpSink {
+    /// Start the dump, before any blocks.
+    fn begin(&mut self, metadata: &DumpMetadata) -> Result<(), ScratchpadError>;
+
+    /// Write the next block of records.
+    fn write_block(&mut self, block: &RecordBlock) -> Result<(), ScratchpadError>;
+
+    /// End the dump, flushing anything buffered.
+    fn finish(self: Box<Self>) -> Result<(), ScratchpadError>;
+}
+
+/// Create a sink for `format`, writing to `dump_filename`, or stdout if it's
+/// `None` or `-`. `vis_radix` only applies to CSV.
+pub fn create_sink(
+    format: DumpFormat,
+    dump_filename: Option<&Path>,
+    compression: Option<Compression>,
+    vis_radix: u8,
+) -> Result<Box<dyn DumpSink>, ScratchpadError> {
+    if format == DumpFormat::Fits || format == DumpFormat::Parquet {
+        return match (dump_filename, compression) {
+            (Some(path), None) | (Some(path), Some(Compression::None))
+                if path != Path::new("-") =>
+            {
+                Ok(match format {
+                    DumpFormat::Fits => Box::new(FitsSink::create(&path)?),
+                    _ => Box::new(ParquetSink::create(&path)?),
+                })
+            }
+            _ => Err(ScratchpadError::InvalidArgument(format!(
+                "{} dumps need a dump filename, and can't be compressed",
+                if format == DumpFormat::Fits {
+                    "fits"
+                } else {
+                    "parquet"
+                }
+            ))),
+        };
+    }
+    let writer = match dump_filename {
+        Some(path) => DumpWriter::create(&path, compression)?,
+        None => DumpWriter::stdout(compression)?,
+    };
+    Ok(match format {
+        DumpFormat::Json => Box::new(JsonSink::new(writer)),
+        _ => Box::new(CsvSink::new(writer, vis_radix)),
+    })
+}
+
+/// One row per record, with a header.
+pub struct CsvSink {
+    writer: DumpWriter,
+    vis_radix: u8,
+    flagged: bool,
+}
+
+impl CsvSink {
+    pub fn new(writer: DumpWriter, vis_radix: u8) -> Self {
+        CsvSink {
+            writer,
+            vis_radix,
+            flagged: false,
+        }
+    }
+}
+
+impl DumpSink for CsvSink {
+    fn begin(&mut self, metadata: &DumpMetadata) -> Result<(), ScratchpadError> {
+        self.flagged = metadata.flagged;
+        metadata.provenance.write_comments(&mut self.writer)?;
+        write!(
+            self.writer,
+            "coarse_chan,timestep,baseline,ant1_name,ant2_name,fine_chan,xx_re,xx_im,xy_re,xy_im,yx_re,yx_im,yy_re,yy_im"
+        )?;
+        if metadata.uvw {
+            write!(self.writer, ",u_m,v_m,w_m,u_lambda,v_lambda,w_lambda")?;
+        }
+        if metadata.flagged {
+            write!(self.writer, ",flagged")?;
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    fn write_block(&mut self, block: &RecordBlock) -> Result<(), ScratchpadError> {
+        for record in block.records.iter() {
+            write!(
+                self.writer,
+                "{},{},{},{},{},{}",
+                record.coarse_chan,
+                record.timestep,
+                record.baseline,
+                record.ant1_name,
+                record.ant2_name,
+                record.fine_chan,
+            )?;
+            for pol in record.vis.iter() {
+                for &float_val in &[pol.re, pol.im] {
+                    if self.vis_radix > 0 {
+                        write!(self.writer, ",{}", radix(float_val as u64, self.vis_radix))?;
+                    } else {
+                        write!(self.writer, ",{}", float_val)?;
+                    }
+                }
+            }
+            if let Some(uvw_m) = block.uvws.get(record.baseline) {
+                let uvw_lambda = uvw_m.to_wavelengths(record.freq_hz);
+                write!(
+                    self.writer,
+                    ",{},{},{},{},{},{}",
+                    uvw_m.u, uvw_m.v, uvw_m.w, uvw_lambda.u, uvw_lambda.v, uvw_lambda.w
+                )?;
+            }
+            if self.flagged {
+                write!(self.writer, ",{}", record.flagged)?;
+            }
+            writeln!(self.writer)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), ScratchpadError> {
+        self.writer.finish()
+    }
+}
+
+/// A record as it appears in JSON.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    coarse_chan: usize,
+    timestep: usize,
+    baseline: usize,
+    ant1: usize,
+    ant2: usize,
+    ant1_name: &'a str,
+    ant2_name: &'a str,
+    fine_chan: usize,
+    freq_hz: f64,
+    gps_time_ms: u64,
+    xx: [f32; 2],
+    xy: [f32; 2],
+    yx: [f32; 2],
+    yy: [f32; 2],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uvw_m: Option<[f64; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uvw_lambda: Option<[f64; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flagged: Option<bool>,
+}
+
+/// A single document of `{"metadata": {...}, "records": [...]}`, with a
+/// record per line so it can be streamed.
+pub struct JsonSink {
+    writer: DumpWriter,
+    flagged: bool,
+    first: bool,
+}
+
+impl JsonSink {
+    pub fn new(writer: DumpWriter) -> Self {
+        JsonSink {
+            writer,
+            flagged: false,
+            first: true,
+        }
+    }
+}
+
+impl DumpSink for JsonSink {
+    fn begin(&mut self, metadata: &DumpMetadata) -> Result<(), ScratchpadError> {
+        self.flagged = metadata.flagged;
+        writeln!(
+            self.writer,
+            "{{\"metadata\":{},\"records\":[",
+            serde_json::to_string(metadata)?
+        )?;
+        Ok(())
+    }
+
+    fn write_block(&mut self, block: &RecordBlock) -> Result<(), ScratchpadError> {
+        for record in block.records.iter() {
+            let [xx, xy, yx, yy] = record.vis;
+            let uvw_m = block.uvws.get(record.baseline);
+            let json_record = JsonRecord {
+                coarse_chan: record.coarse_chan,
+                timestep: record.timestep,
+                baseline: record.baseline,
+                ant1: record.ant1,
+                ant2: record.ant2,
+                ant1_name: &record.ant1_name,
+                ant2_name: &record.ant2_name,
+                fine_chan: record.fine_chan,
+                freq_hz: record.freq_hz,
+                gps_time_ms: record.gps_time_ms,
+                xx: [xx.re, xx.im],
+                xy: [xy.re, xy.im],
+                yx: [yx.re, yx.im],
+                yy: [yy.re, yy.im],
+                uvw_m: uvw_m.map(|uvw_m| [uvw_m.u, uvw_m.v, uvw_m.w]),
+                uvw_lambda: uvw_m.map(|uvw_m| {
+                    let uvw_lambda = uvw_m.to_wavelengths(record.freq_hz);
+                    [uvw_lambda.u, uvw_lambda.v, uvw_lambda.w]
+                }),
+                flagged: if self.flagged {
+                    Some(record.flagged)
+                } else {
+                    None
+                },
+            };
+            // Serialize first so a closed pipe is reported as an io error
+            let line = serde_json::to_string(&json_record)?;
+            if !self.first {
+                writeln!(self.writer, ",")?;
+            }
+            self.writer.write_all(line.as_bytes())?;
+            self.first = false;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), ScratchpadError> {
+        writeln!(self.writer, "\n]}}")?;
+        self.writer.finish()
+    }
+}
+
+/// A binary table named VIS with a row per record, and the metadata in the
+/// primary header.
+pub struct FitsSink {
+    fptr: FitsFile,
+    hdu: Option<FitsHdu>,
+    flagged: bool,
+    num_rows: usize,
+}
+
+impl FitsSink {
+    /// Create a FITS file, overwriting any existing file.
+    pub fn create<T: AsRef<Path>>(path: &T) -> Result<Self, ScratchpadError> {
+        Ok(FitsSink {
+            fptr: FitsFile::create(path).overwrite().open()?,
+            hdu: None,
+            flagged: false,
+            num_rows: 0,
+        })
+    }
+}
+
+impl DumpSink for FitsSink {
+    fn begin(&mut self, metadata: &DumpMetadata) -> Result<(), ScratchpadError> {
+        let fptr = &mut self.fptr;
+        let primary_hdu = fptr.primary_hdu()?;
+        primary_hdu.write_key(fptr, "OBSID", metadata.obs_id)?;
+        primary_hdu.write_key(fptr, "CORRVER", metadata.corr_version.as_str())?;
+        primary_hdu.write_key(fptr, "NCOARSE", metadata.num_coarse_chans as u64)?;
+        primary_hdu.write_key(fptr, "NSCANS", metadata.num_timesteps as u64)?;
+        primary_hdu.write_key(fptr, "NBASELN", metadata.num_baselines as u64)?;
+        primary_hdu.write_key(fptr, "NCHANS", metadata.num_fine_chans_per_coarse as u64)?;
+        primary_hdu.write_key(fptr, "FINECHAN", metadata.fine_chan_width_hz)?;
+        primary_hdu.write_key(fptr, "INTTIME", metadata.int_time_ms)?;
+        let provenance = &metadata.provenance;
+        primary_hdu.write_key(
+            fptr,
+            "SOFTWARE",
+            format!("{} {}", provenance.software, provenance.version),
+        )?;
+        primary_hdu.write_key(fptr, "GITHASH", provenance.git_hash.as_str())?;
+        primary_hdu.write_key(fptr, "MWALIBV", provenance.mwalib_version.as_str())?;
+        // FITS dates have no time zone, and are UTC
+        primary_hdu.write_key(fptr, "DATE", provenance.created_utc.trim_end_matches('Z'))?;
+        // The command line and inputs are too long for keys
+        for line in provenance.lines() {
+            let line = CString::new(line).map_err(|err| {
+                ScratchpadError::InvalidArgument(format!("provenance has a nul: {}", err))
+            })?;
+            let mut status = 0;
+            unsafe {
+                fitsio_sys::ffphis(fptr.as_raw(), line.as_ptr(), &mut status);
+            }
+            check_status(status)?;
+        }
+
+        let mut columns: Vec<(&str, ColumnDataType)> = vec![
+            ("COARSE_CHAN", ColumnDataType::Int),
+            ("TIMESTEP", ColumnDataType::Int),
+            ("BASELINE", ColumnDataType::Int),
+            ("ANT1", ColumnDataType::Int),
+            ("ANT2", ColumnDataType::Int),
+            ("FINE_CHAN", ColumnDataType::Int),
+            ("FREQ_HZ", ColumnDataType::Double),
+            ("GPS_TIME_MS", ColumnDataType::Long),
+        ];
+        for name in FITS_VIS_COLUMNS.iter() {
+            columns.push((name, ColumnDataType::Float));
+        }
+        if metadata.uvw {
+            for name in FITS_UVW_COLUMNS.iter() {
+                columns.push((name, ColumnDataType::Double));
+            }
+        }
+        if metadata.flagged {
+            columns.push(("FLAGGED", ColumnDataType::Int));
+        }
+        self.flagged = metadata.flagged;
+        let columns = columns
+            .into_iter()
+            .map(|(name, data_type)| ColumnDescription::new(name).with_type(data_type).create())
+            .collect::<Result<Vec<_>, _>>()?;
+        self.hdu = Some(fptr.create_table("VIS", &columns)?);
+        Ok(())
+    }
+
+    fn write_block(&mut self, block: &RecordBlock) -> Result<(), ScratchpadError> {
+        let hdu = match &self.hdu {
+            Some(hdu) => hdu,
+            None => {
+                return Err(ScratchpadError::InvalidArgument(
+                    "fits dump written to before it began".to_string(),
+                ))
+            }
+        };
+        let records = &block.records;
+        if records.is_empty() {
+            return Ok(());
+        }
+        let rows = self.num_rows..self.num_rows + records.len();
+        let int_col = |value: fn(&VisRecord) -> usize| -> Vec<i32> {
+            records.iter().map(|record| value(record) as i32).collect()
+        };
+        let int_columns = [
+            ("COARSE_CHAN", int_col(|record| record.coarse_chan)),
+            ("TIMESTEP", int_col(|record| record.timestep)),
+            ("BASELINE", int_col(|record| record.baseline)),
+            ("ANT1", int_col(|record| record.ant1)),
+            ("ANT2", int_col(|record| record.ant2)),
+            ("FINE_CHAN", int_col(|record| record.fine_chan)),
+        ];
+        for (name, col) in int_columns.iter() {
+            hdu.write_col_range(&mut self.fptr, *name, col, &rows)?;
+        }
+        let freqs_hz: Vec<f64> = records.iter().map(|record| record.freq_hz).collect();
+        hdu.write_col_range(&mut self.fptr, "FREQ_HZ", &freqs_hz, &rows)?;
+        let gps_times_ms: Vec<i64> = records
+            .iter()
+            .map(|record| record.gps_time_ms as i64)
+            .collect();
+        hdu.write_col_range(&mut self.fptr, "GPS_TIME_MS", &gps_times_ms, &rows)?;
+        for (float_index, name) in FITS_VIS_COLUMNS.iter().enumerate() {
+            let col: Vec<f32> = records
+                .iter()
+                .map(|record| {
+                    let pol = record.vis[float_index / 2];
+                    if float_index % 2 == 0 {
+                        pol.re
+                    } else {
+                        pol.im
+                    }
+                })
+                .collect();
+            hdu.write_col_range(&mut self.fptr, *name, &col, &rows)?;
+        }
+        if !block.uvws.is_empty() {
+            for (uvw_index, name) in FITS_UVW_COLUMNS.iter().enumerate() {
+                let col: Vec<f64> = records
+                    .iter()
+                    .map(|record| {
+                        let uvw_m = block.uvws[record.baseline];
+                        let uvw = if uvw_index < 3 {
+                            uvw_m
+                        } else {
+                            uvw_m.to_wavelengths(record.freq_hz)
+                        };
+                        [uvw.u, uvw.v, uvw.w][uvw_index % 3]
+                    })
+                    .collect();
+                hdu.write_col_range(&mut self.fptr, *name, &col, &rows)?;
+            }
+        }
+        if self.flagged {
+            let col: Vec<i32> = records.iter().map(|record| record.flagged as i32).collect();
+            hdu.write_col_range(&mut self.fptr, "FLAGGED", &col, &rows)?;
+        }
+        self.num_rows = rows.end;
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), ScratchpadError> {
+        // Flush here to see any error, as closing the file when it's dropped can't report one
+        let mut status = 0;
+        unsafe {
+            fitsio_sys::ffflus(self.fptr.as_raw(), &mut status);
+        }
+        check_status(status)?;
+        Ok(())
+    }
+}
+
+/// A Parquet file with a column per field and optional column, compressed
+/// with Snappy, and the metadata as JSON under the `metadata` key.
+pub struct ParquetSink {
+    file: Option<File>,
+    writer: Option<ArrowWriter<File>>,
+    schema: SchemaRef,
+    flagged: bool,
+}
+
+impl ParquetSink {
+    /// Create a Parquet file, overwriting any existing file.
+    pub fn create<T: AsRef<Path>>(path: &T) -> Result<Self, ScratchpadError> {
+        Ok(ParquetSink {
+            file: Some(File::create(path)?),
+            writer: None,
+            schema: Arc::new(Schema::empty()),
+            flagged: false,
+        })
+    }
+}
+
+impl DumpSink for ParquetSink {
+    fn begin(&mut self, metadata: &DumpMetadata) -> Result<(), ScratchpadError> {
+        let mut fields = vec![
+            Field::new("coarse_chan", DataType::UInt32, false),
+            Field::new("timestep", DataType::UInt32, false),
+            Field::new("baseline", DataType::UInt32, false),
+            Field::new("ant1", DataType::UInt32, false),
+            Field::new("ant2", DataType::UInt32, false),
+            Field::new("ant1_name", DataType::Utf8, false),
+            Field::new("ant2_name", DataType::Utf8, false),
+            Field::new("fine_chan", DataType::UInt32, false),
+            Field::new("freq_hz", DataType::Float64, false),
+            Field::new("gps_time_ms", DataType::UInt64, false),
+        ];
+        for name in PARQUET_VIS_COLUMNS.iter() {
+            fields.push(Field::new(*name, DataType::Float32, false));
+        }
+        if metadata.uvw {
+            for name in PARQUET_UVW_COLUMNS.iter() {
+                fields.push(Field::new(*name, DataType::Float64, false));
+            }
+        }
+        if metadata.flagged {
+            fields.push(Field::new("flagged", DataType::Boolean, false));
+        }
+        self.flagged = metadata.flagged;
+        let mut schema_metadata = HashMap::new();
+        schema_metadata.insert("metadata".to_string(), serde_json::to_string(metadata)?);
+        self.schema = Arc::new(Schema::new_with_metadata(fields, schema_metadata));
+
+        let file = match self.file.take() {
+            Some(file) => file,
+            None => {
+                return Err(ScratchpadError::InvalidArgument(
+                    "parquet dump began twice".to_string(),
+                ))
+            }
+        };
+        let properties = WriterProperties::builder()
+            .set_compression(ParquetCompression::SNAPPY)
+            .build();
+        self.writer = Some(ArrowWriter::try_new(
+            file,
+            self.schema.clone(),
+            Some(properties),
+        )?);
+        Ok(())
+    }
+
+    fn write_block(&mut self, block: &RecordBlock) -> Result<(), ScratchpadError> {
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => {
+                return Err(ScratchpadError::InvalidArgument(
+                    "parquet dump written to before it began".to_string(),
+                ))
+            }
+        };
+        let records = &block.records;
+        if records.is_empty() {
+            return Ok(());
+        }
+        let u32_col = |value: fn(&VisRecord) -> usize| -> ArrayRef {
+            Arc::new(UInt32Array::from_iter_values(
+                records.iter().map(|record| value(record) as u32),
+            ))
+        };
+        let mut columns: Vec<ArrayRef> = vec![
+            u32_col(|record| record.coarse_chan),
+            u32_col(|record| record.timestep),
+            u32_col(|record| record.baseline),
+            u32_col(|record| record.ant1),
+            u32_col(|record| record.ant2),
+            Arc::new(StringArray::from_iter_values(
+                records.iter().map(|record| &*record.ant1_name),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                records.iter().map(|record| &*record.ant2_name),
+            )),
+            u32_col(|record| record.fine_chan),
+            Arc::new(Float64Array::from_iter_values(
+                records.iter().map(|record| record.freq_hz),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                records.iter().map(|record| record.gps_time_ms),
+            )),
+        ];
+        for float_index in 0..PARQUET_VIS_COLUMNS.len() {
+            columns.push(Arc::new(Float32Array::from_iter_values(
+                records.iter().map(|record| {
+                    let pol = record.vis[float_index / 2];
+                    if float_index % 2 == 0 {
+                        pol.re
+                    } else {
+                        pol.im
+                    }
+                }),
+            )));
+        }
+        if !block.uvws.is_empty() {
+            for uvw_index in 0..PARQUET_UVW_COLUMNS.len() {
+                columns.push(Arc::new(Float64Array::from_iter_values(
+                    records.iter().map(|record| {
+                        let uvw_m = block.uvws[record.baseline];
+                        let uvw = if uvw_index < 3 {
+                            uvw_m
+                        } else {
+                            uvw_m.to_wavelengths(record.freq_hz)
+                        };
+                        [uvw.u, uvw.v, uvw.w][uvw_index % 3]
+                    }),
+                )));
+            }
+        }
+        if self.flagged {
+            columns.push(Arc::new(
+                records
+                    .iter()
+                    .map(|record| Some(record.flagged))
+                    .collect::<BooleanArray>(),
+            ));
+        }
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)
+            .map_err(parquet::errors::ParquetError::from)?;
+        writer.write(&batch)?;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), ScratchpadError> {
+        if let Some(writer) = self.writer {
+            writer.close()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mwalib::CorrelatorVersion;
+    use num_complex::Complex;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::ffi::OsString;
+
+    fn record(baseline: usize, fine_chan: usize) -> VisRecord {
+        VisRecord {
+            coarse_chan: 0,
+            timestep: 1,
+            baseline,
+            ant1: 0,
+            ant2: baseline,
+            ant1_name: Arc::from("Tile011"),
+            ant2_name: Arc::from(format!("Tile01{}", baseline + 1).as_str()),
+            fine_chan,
+            freq_hz: 150e6,
+            gps_time_ms: 1_297_526_450_000,
+            unix_time_ms: 1_613_491_232_000,
+            vis: [
+                Complex::new(1., -1.),
+                Complex::new(2., -2.),
+                Complex::new(3., -3.),
+                Complex::new(fine_chan as f32, baseline as f32),
+            ],
+            flagged: fine_chan == 1,
+        }
+    }
+
+    #[test]
+    fn test_dump_format_from_path() {
+        assert_eq!(DumpFormat::from_path(&"dump.csv"), DumpFormat::Csv);
+        assert_eq!(DumpFormat::from_path(&"dump.json.gz"), DumpFormat::Json);
+        assert_eq!(DumpFormat::from_path(&"dump.fits"), DumpFormat::Fits);
+        assert_eq!(DumpFormat::from_path(&"dump.parquet"), DumpFormat::Parquet);
+        assert_eq!(DumpFormat::from_path(&"dump"), DumpFormat::Csv);
+    }
+
+    fn metadata() -> DumpMetadata {
+        DumpMetadata {
+            obs_id: 1_297_526_432,
+            corr_version: CorrelatorVersion::V2.to_string(),
+            num_coarse_chans: 1,
+            num_timesteps: 2,
+            num_baselines: 2,
+            num_fine_chans_per_coarse: 2,
+            fine_chan_width_hz: 640_000,
+            int_time_ms: 2000,
+            uvw: true,
+            flagged: true,
+            provenance: Provenance::new::<&Path>(
+                &[
+                    OsString::from("mwa-scratchpad"),
+                    OsString::from("dump-all-data"),
+                ],
+                &[],
+                CorrelatorVersion::V2,
+                true,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn block() -> RecordBlock {
+        RecordBlock {
+            records: vec![record(0, 0), record(0, 1), record(1, 0), record(1, 1)],
+            uvws: vec![
+                Uvw {
+                    u: 0.,
+                    v: 0.,
+                    w: 0.,
+                },
+                Uvw {
+                    u: 10.,
+                    v: -20.,
+                    w: 1.,
+                },
+            ],
+        }
+    }
+
+    /// Write `block` to `sink` between its metadata and finish.
+    fn write_dump(mut sink: Box<dyn DumpSink>, metadata: &DumpMetadata, block: &RecordBlock) {
+        sink.begin(metadata).unwrap();
+        sink.write_block(block).unwrap();
+        sink.write_block(&RecordBlock {
+            records: vec![],
+            uvws: vec![],
+        })
+        .unwrap();
+        sink.finish().unwrap();
+    }
+
+    fn test_path(extension: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "mwa-scratchpad-test-sink-{}.{}",
+            std::process::id(),
+            extension
+        ))
+    }
+
+    #[test]
+    fn test_csv_sink() {
+        let path = test_path("csv");
+        let writer = DumpWriter::create(&path, None).unwrap();
+        write_dump(Box::new(CsvSink::new(writer, 0)), &metadata(), &block());
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let (comments, rows): (Vec<&str>, Vec<&str>) =
+            contents.lines().partition(|line| line.starts_with("# "));
+        assert_eq!(comments.len(), metadata().provenance.lines().len());
+        assert_eq!(
+            rows[0],
+            "coarse_chan,timestep,baseline,ant1_name,ant2_name,fine_chan,xx_re,xx_im,xy_re,xy_im,yx_re,yx_im,yy_re,yy_im,u_m,v_m,w_m,u_lambda,v_lambda,w_lambda,flagged"
+        );
+        assert_eq!(rows.len(), 5);
+        let uvw_lambda = block().uvws[1].to_wavelengths(150e6);
+        assert_eq!(
+            rows[4],
+            format!(
+                "0,1,1,Tile011,Tile012,1,1,-1,2,-2,3,-3,1,1,10,-20,1,{},{},{},true",
+                uvw_lambda.u, uvw_lambda.v, uvw_lambda.w
+            )
+        );
+    }
+
+    #[test]
+    fn test_csv_sink_radix() {
+        let path = test_path("radix.csv");
+        let writer = DumpWriter::create(&path, None).unwrap();
+        let metadata = DumpMetadata {
+            uvw: false,
+            flagged: false,
+            ..metadata()
+        };
+        let block = RecordBlock {
+            records: vec![record(1, 3)],
+            uvws: vec![],
+        };
+        write_dump(Box::new(CsvSink::new(writer, 16)), &metadata, &block);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            contents.lines().last().unwrap(),
+            "0,1,1,Tile011,Tile012,3,1,0,2,0,3,0,3,1"
+        );
+    }
+
+    #[test]
+    fn test_json_sink() {
+        let path = test_path("json");
+        let writer = DumpWriter::create(&path, None).unwrap();
+        write_dump(Box::new(JsonSink::new(writer)), &metadata(), &block());
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let dump: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(dump["metadata"]["obs_id"], 1_297_526_432);
+        assert_eq!(dump["metadata"]["num_baselines"], 2);
+        assert_eq!(
+            dump["metadata"]["provenance"]["software"],
+            env!("CARGO_PKG_NAME")
+        );
+        let records = dump["records"].as_array().unwrap();
+        assert_eq!(records.len(), 4);
+        let record = &records[3];
+        assert_eq!(record["baseline"], 1);
+        assert_eq!(record["ant2_name"], "Tile012");
+        assert_eq!(record["fine_chan"], 1);
+        assert_eq!(record["gps_time_ms"], 1_297_526_450_000_u64);
+        assert_eq!(record["yy"], serde_json::json!([1., 1.]));
+        assert_eq!(record["uvw_m"], serde_json::json!([10., -20., 1.]));
+        assert_eq!(record["flagged"], true);
+    }
+
+    #[test]
+    fn test_fits_sink() {
+        let path = test_path("fits");
+        let metadata = metadata();
+        write_dump(
+            Box::new(FitsSink::create(&path).unwrap()),
+            &metadata,
+            &block(),
+        );
+        let mut fptr = FitsFile::open(&path).unwrap();
+        let primary_hdu = fptr.primary_hdu().unwrap();
+        let obs_id: i64 = primary_hdu.read_key(&mut fptr, "OBSID").unwrap();
+        let software: String = primary_hdu.read_key(&mut fptr, "SOFTWARE").unwrap();
+        let hdu = fptr.hdu("VIS").unwrap();
+        let yy_re: Vec<f32> = hdu.read_col(&mut fptr, "YY_RE").unwrap();
+        let v_m: Vec<f64> = hdu.read_col(&mut fptr, "V_M").unwrap();
+        let flagged: Vec<i32> = hdu.read_col(&mut fptr, "FLAGGED").unwrap();
+        drop(fptr);
+        let header = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Header cards are 80 characters, unterminated
+        let history: Vec<String> = header[..2880]
+            .chunks(80)
+            .map(String::from_utf8_lossy)
+            .filter(|card| card.starts_with("HISTORY "))
+            .map(|card| card["HISTORY ".len()..].trim_end().to_string())
+            .collect();
+        assert_eq!(history, metadata.provenance.lines());
+        assert!(history[0].starts_with("software: "));
+
+        assert_eq!(obs_id, 1_297_526_432);
+        assert!(software.starts_with(env!("CARGO_PKG_NAME")));
+        assert_eq!(yy_re, [0., 1., 0., 1.]);
+        assert_eq!(v_m, [0., 0., -20., -20.]);
+        assert_eq!(flagged, [0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_parquet_sink() {
+        let path = test_path("parquet");
+        write_dump(
+            Box::new(ParquetSink::create(&path).unwrap()),
+            &metadata(),
+            &block(),
+        );
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        let schema = builder.schema().clone();
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let metadata_json: serde_json::Value =
+            serde_json::from_str(&schema.metadata()["metadata"]).unwrap();
+        assert_eq!(metadata_json["obs_id"], 1_297_526_432);
+        assert_eq!(
+            metadata_json["provenance"]["software"],
+            env!("CARGO_PKG_NAME")
+        );
+        assert_eq!(schema.fields().len(), 10 + 8 + 6 + 1);
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            4
+        );
+        let batch = &batches[0];
+        let column = |name: &str| batch.column(schema.index_of(name).unwrap()).clone();
+        let ant2_names = column("ant2_name");
+        let ant2_names = ant2_names.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(ant2_names.value(2), "Tile012");
+        let yy_re = column("yy_re");
+        let yy_re = yy_re.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(yy_re.values().to_vec(), vec![0., 1., 0., 1.]);
+        let v_m = column("v_m");
+        let v_m = v_m.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(v_m.values().to_vec(), vec![0., 0., -20., -20.]);
+        let flagged = column("flagged");
+        let flagged = flagged.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(
+            flagged.iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), Some(false), Some(true)]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Given gpubox files, report which HDUs are present for each timestep and
+/// coarse channel, to diagnose incomplete data transfers.
+use crate::gpubox::TimeMapCoverage;
+use crate::ScratchpadError;
+use mwalib::CorrelatorContext;
+use structopt::StructOpt;
+
+#[cfg(not(tarpaulin_include))]
+#[derive(StructOpt, Debug)]
+#[structopt(name = "mwa-time-map", author)]
+pub struct TimeMapOpt {
+    /// Path to the metafits file.
+    #[structopt(short, long, parse(from_os_str))]
+    pub metafits: std::path::PathBuf,
+
+    /// Paths to the gpubox files.
+    #[structopt(name = "GPUBOX FILE", parse(from_os_str))]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Find the metafits and gpubox files of the observation in this directory, instead of listing
+    /// them
+    #[structopt(long, parse(from_os_str))]
+    pub obs_dir: Option<std::path::PathBuf>,
+
+    /// Print the coverage as JSON instead of a grid
+    #[structopt(short, long)]
+    pub json: bool,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn print_time_map<T: AsRef<std::path::Path>>(
+    metafits: &T,
+    files: &[T],
+    json: bool,
+) -> Result<(), ScratchpadError> {
+    let context = CorrelatorContext::new(metafits, files)?;
+    let coverage = TimeMapCoverage::new(&context, files)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&coverage)?);
+        return Ok(());
+    }
+
+    println!("gpubox HDU coverage by timestep and coarse channel (# present, . missing)");
+    print!("{:>14} {:>8} ", "unix_time_ms", "timestep");
+    for gpubox_number in &coverage.gpubox_numbers {
+        print!("{:>4}", gpubox_number);
+    }
+    println!();
+    for row in &coverage.rows {
+        let timestep = match row.timestep {
+            Some(timestep_index) => timestep_index.to_string(),
+            None => "-".to_string(),
+        };
+        print!("{:>14} {:>8} ", row.unix_time_ms, timestep);
+        for hdu in &row.hdus {
+            print!("{:>4}", if hdu.is_some() { "#" } else { "." });
+        }
+        println!();
+    }
+
+    println!(
+        "{} gaps over {} timesteps and {} coarse channels",
+        coverage.gaps.len(),
+        coverage.rows.len(),
+        coverage.gpubox_numbers.len()
+    );
+    for gap in &coverage.gaps {
+        println!(
+            "missing gpubox {:>3} at {}",
+            gap.gpubox_number, gap.unix_time_ms
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Baseline geometry in the UVW frame, for sanity checks alongside raw
+/// visibilities. This ignores precession, nutation and UT1-UTC, which is fine
+/// for sanity checks but not for calibration.
+use chrono::{TimeZone, Utc};
+use mwalib::{MetafitsContext, MWA_LATITUDE_RADIANS, MWA_LONGITUDE_RADIANS};
+
+pub const SPEED_OF_LIGHT_M_S: f64 = 299_792_458.0;
+
+/// A position or separation in local East, North, Up coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct Enu {
+    pub e: f64,
+    pub n: f64,
+    pub u: f64,
+}
+
+impl Enu {
+    /// Rotate into the equatorial XYZ frame at `latitude_rad`.
+    pub fn to_xyz(self, latitude_rad: f64) -> Xyz {
+        let (sin_lat, cos_lat) = latitude_rad.sin_cos();
+        Xyz {
+            x: -sin_lat * self.n + cos_lat * self.u,
+            y: self.e,
+            z: cos_lat * self.n + sin_lat * self.u,
+        }
+    }
+}
+
+/// A separation in the equatorial frame: X towards (HA=0, Dec=0), Z towards the pole.
+#[derive(Debug, Clone, Copy)]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Uvw {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+}
+
+impl Uvw {
+    /// Project a baseline onto the plane of a phase centre at the given hour angle and declination.
+    pub fn from_xyz(xyz: Xyz, hour_angle_rad: f64, dec_rad: f64) -> Self {
+        let (sin_ha, cos_ha) = hour_angle_rad.sin_cos();
+        let (sin_dec, cos_dec) = dec_rad.sin_cos();
+        Uvw {
+            u: sin_ha * xyz.x + cos_ha * xyz.y,
+            v: -sin_dec * cos_ha * xyz.x + sin_dec * sin_ha * xyz.y + cos_dec * xyz.z,
+            w: cos_dec * cos_ha * xyz.x - cos_dec * sin_ha * xyz.y + sin_dec * xyz.z,
+        }
+    }
+
+    /// Convert from metres to wavelengths at `freq_hz`.
+    pub fn to_wavelengths(self, freq_hz: f64) -> Self {
+        let scale = freq_hz / SPEED_OF_LIGHT_M_S;
+        Uvw {
+            u: self.u * scale,
+            v: self.v * scale,
+            w: self.w * scale,
+        }
+    }
+}
+
+/// The direction visibilities are phased towards.
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseCentre {
+    pub ra_rad: f64,
+    pub dec_rad: f64,
+}
+
+impl PhaseCentre {
+    /// The metafits phase centre, or the tile pointing centre if there isn't one.
+    pub fn from_metafits(context: &MetafitsContext) -> Self {
+        PhaseCentre {
+            ra_rad: context
+                .ra_phase_center_degrees
+                .unwrap_or(context.ra_tile_pointing_degrees)
+                .to_radians(),
+            dec_rad: context
+                .dec_phase_center_degrees
+                .unwrap_or(context.dec_tile_pointing_degrees)
+                .to_radians(),
+        }
+    }
+
+    /// A phase centre given in degrees.
+    pub fn from_degrees(ra_deg: f64, dec_deg: f64) -> Self {
+        PhaseCentre {
+            ra_rad: ra_deg.to_radians(),
+            dec_rad: dec_deg.to_radians(),
+        }
+    }
+
+    /// The zenith at the MWA, which raw visibilities are phased towards.
+    pub fn zenith(unix_time_ms: u64) -> Self {
+        PhaseCentre {
+            ra_rad: lst_rad(unix_time_ms),
+            dec_rad: MWA_LATITUDE_RADIANS,
+        }
+    }
+}
+
+/// Local sidereal time in radians at the MWA, from the GMST of a unix time
+/// (treating UTC as UT1).
+pub fn lst_rad(unix_time_ms: u64) -> f64 {
+    let time = Utc.timestamp_millis_opt(unix_time_ms as i64).unwrap();
+    let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+    let days_since_j2000 = (time - j2000).num_milliseconds() as f64 / 86_400_000.0;
+    let gmst_deg = 280.460_618_37 + 360.985_647_366_29 * days_since_j2000;
+    (gmst_deg.to_radians() + MWA_LONGITUDE_RADIANS).rem_euclid(std::f64::consts::TAU)
+}
+
+/// The separation of every baseline (in baseline index order) in the
+/// equatorial frame, in metres.
+pub fn baseline_xyzs(context: &MetafitsContext) -> Vec<Xyz> {
+    let tile_xyzs: Vec<Xyz> = context
+        .antennas
+        .iter()
+        .map(|antenna| {
+            Enu {
+                e: antenna.rfinput_x.east_m,
+                n: antenna.rfinput_x.north_m,
+                u: antenna.rfinput_x.height_m,
+            }
+            .to_xyz(MWA_LATITUDE_RADIANS)
+        })
+        .collect();
+    context
+        .baselines
+        .iter()
+        .map(|baseline| {
+            let xyz1 = tile_xyzs[baseline.ant1_index];
+            let xyz2 = tile_xyzs[baseline.ant2_index];
+            Xyz {
+                x: xyz2.x - xyz1.x,
+                y: xyz2.y - xyz1.y,
+                z: xyz2.z - xyz1.z,
+            }
+        })
+        .collect()
+}
+
+/// The UVW of each baseline separation in metres, towards `phase_centre` at
+/// `unix_time_ms`.
+pub fn uvws_towards(separations: &[Xyz], phase_centre: PhaseCentre, unix_time_ms: u64) -> Vec<Uvw> {
+    let hour_angle_rad = lst_rad(unix_time_ms) - phase_centre.ra_rad;
+    separations
+        .iter()
+        .map(|&separation| Uvw::from_xyz(separation, hour_angle_rad, phase_centre.dec_rad))
+        .collect()
+}
+
+/// The UVW of every baseline (in baseline index order) in metres, towards
+/// `phase_centre` at `unix_time_ms`.
+pub fn baseline_uvws(
+    context: &MetafitsContext,
+    phase_centre: PhaseCentre,
+    unix_time_ms: u64,
+) -> Vec<Uvw> {
+    uvws_towards(&baseline_xyzs(context), phase_centre, unix_time_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_enu_to_xyz_at_equator() {
+        let xyz = Enu {
+            e: 1.,
+            n: 2.,
+            u: 3.,
+        }
+        .to_xyz(0.);
+        assert_close(xyz.x, 3.);
+        assert_close(xyz.y, 1.);
+        assert_close(xyz.z, 2.);
+    }
+
+    #[test]
+    fn test_enu_to_xyz_at_pole() {
+        let xyz = Enu {
+            e: 1.,
+            n: 2.,
+            u: 3.,
+        }
+        .to_xyz(std::f64::consts::FRAC_PI_2);
+        assert_close(xyz.x, -2.);
+        assert_close(xyz.y, 1.);
+        assert_close(xyz.z, 3.);
+    }
+
+    #[test]
+    fn test_uvw_from_xyz_towards_zenith() {
+        // Towards the zenith, east, north and up map straight onto u, v and w
+        let uvw =
+            |enu: Enu| Uvw::from_xyz(enu.to_xyz(MWA_LATITUDE_RADIANS), 0., MWA_LATITUDE_RADIANS);
+        let east = uvw(Enu {
+            e: 10.,
+            n: 0.,
+            u: 0.,
+        });
+        assert_close(east.u, 10.);
+        assert_close(east.v, 0.);
+        assert_close(east.w, 0.);
+        let north = uvw(Enu {
+            e: 0.,
+            n: 10.,
+            u: 0.,
+        });
+        assert_close(north.u, 0.);
+        assert_close(north.v, 10.);
+        assert_close(north.w, 0.);
+        let up = uvw(Enu {
+            e: 0.,
+            n: 0.,
+            u: 10.,
+        });
+        assert_close(up.u, 0.);
+        assert_close(up.v, 0.);
+        assert_close(up.w, 10.);
+    }
+
+    #[test]
+    fn test_uvw_from_xyz_keeps_length() {
+        let xyz = Enu {
+            e: 123.,
+            n: -45.,
+            u: 6.,
+        }
+        .to_xyz(MWA_LATITUDE_RADIANS);
+        let uvw = Uvw::from_xyz(xyz, 0.7, -0.3);
+        assert_close(
+            (uvw.u * uvw.u + uvw.v * uvw.v + uvw.w * uvw.w).sqrt(),
+            (123_f64 * 123. + 45. * 45. + 6. * 6.).sqrt(),
+        );
+    }
+
+    #[test]
+    fn test_uvw_to_wavelengths() {
+        let uvw = Uvw {
+            u: 1.,
+            v: -2.,
+            w: 0.5,
+        }
+        .to_wavelengths(SPEED_OF_LIGHT_M_S);
+        assert_close(uvw.u, 1.);
+        assert_close(uvw.v, -2.);
+        assert_close(uvw.w, 0.5);
+    }
+}
@@ -0,0 +1,708 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Read visibilities as typed records, one per baseline and fine channel of
+/// each timestep, with corrections, flags and selection applied in the one
+/// traversal shared by dumps and analyses.
+use crate::channels::fine_chan_freqs_hz;
+use crate::corrections::{
+    correct_cable_lengths, correct_digital_gains, correct_passband, rotate_phase_centre,
+};
+use crate::mwaf::MwafFile;
+use crate::uvw::PhaseCentre;
+use crate::ScratchpadError;
+use log::debug;
+use mwalib::{CorrelatorContext, MwalibError};
+use num_complex::Complex;
+use std::sync::Arc;
+
+/// All four pols of one baseline at one fine channel and timestep.
+#[derive(Debug, Clone)]
+pub struct VisRecord {
+    /// Index into the context's coarse channels.
+    pub coarse_chan: usize,
+    /// Index into the context's timesteps.
+    pub timestep: usize,
+    pub baseline: usize,
+    pub ant1: usize,
+    pub ant2: usize,
+    pub ant1_name: Arc<str>,
+    pub ant2_name: Arc<str>,
+    /// Index of the fine channel within its coarse channel.
+    pub fine_chan: usize,
+    /// Centre frequency of the fine channel.
+    pub freq_hz: f64,
+    /// Start of the timestep.
+    pub gps_time_ms: u64,
+    pub unix_time_ms: u64,
+    /// XX, XY, YX, YY.
+    pub vis: [Complex<f32>; 4],
+    pub flagged: bool,
+}
+
+/// Which visibilities to read. An empty list selects everything.
+#[derive(Debug, Clone, Default)]
+pub struct VisSelection {
+    pub coarse_chans: Vec<usize>,
+    pub timesteps: Vec<usize>,
+    pub baselines: Vec<usize>,
+    /// Fine channel indices within each coarse channel.
+    pub fine_chans: Vec<usize>,
+    /// Leave out flagged visibilities instead of marking them.
+    pub drop_flagged: bool,
+}
+
+/// Corrections to apply, in the order listed.
+#[derive(Debug, Clone, Default)]
+pub struct VisCorrections {
+    pub digital_gains: bool,
+    /// Gains across one coarse channel, from `Passband::gains`.
+    pub passband: Option<Vec<f64>>,
+    pub cable_delay: bool,
+    /// Re-phase from the zenith to this phase centre.
+    pub phase_centre: Option<PhaseCentre>,
+}
+
+/// Where flags come from.
+#[derive(Debug, Clone, Default)]
+pub struct VisFlagging {
+    /// Flag baselines with a tile flagged in the metafits.
+    pub flag_tiles: bool,
+    /// Flag timesteps before the metafits good time.
+    pub flag_quack: bool,
+    /// Flags of each coarse channel, from `read_mwafs_for_context`.
+    pub mwafs: Vec<Option<MwafFile>>,
+}
+
+/// Check a selection of indices against `len`, returning every index if none
+/// are selected, otherwise the selected ones in ascending order.
+fn resolve_indices(
+    selected: &[usize],
+    len: usize,
+    what: &str,
+) -> Result<Vec<usize>, ScratchpadError> {
+    if selected.is_empty() {
+        return Ok((0..len).collect());
+    }
+    if let Some(index) = selected.iter().find(|&&index| index >= len) {
+        return Err(ScratchpadError::InvalidArgument(format!(
+            "{} {} is out of range, there are {}",
+            what, index, len
+        )));
+    }
+    let mut indices = selected.to_vec();
+    indices.sort_unstable();
+    indices.dedup();
+    Ok(indices)
+}
+
+/// Everything needed to read any selected timestep of any coarse channel,
+/// resolved against a context once up front so it can be shared between
+/// threads, each reading with its own context.
+#[derive(Debug, Clone)]
+pub struct VisPlan {
+    corrections: VisCorrections,
+    flagging: VisFlagging,
+    drop_flagged: bool,
+    coarse_chans: Vec<usize>,
+    timesteps: Vec<usize>,
+    /// Selected baselines, with their antenna indices.
+    baselines: Vec<(usize, usize, usize)>,
+    fine_chans: Vec<usize>,
+    // Digital gains are in ascending frequency order of the metafits coarse channels
+    metafits_rec_chan_numbers: Vec<usize>,
+    flagged_ants: Vec<bool>,
+    tile_names: Vec<Arc<str>>,
+}
+
+impl VisPlan {
+    pub fn new(
+        context: &CorrelatorContext,
+        selection: VisSelection,
+        corrections: VisCorrections,
+        flagging: VisFlagging,
+    ) -> Result<Self, ScratchpadError> {
+        let metafits_context = &context.metafits_context;
+        let baselines = resolve_indices(
+            &selection.baselines,
+            metafits_context.num_baselines,
+            "baseline",
+        )?
+        .into_iter()
+        .map(|baseline_index| {
+            let baseline = &metafits_context.baselines[baseline_index];
+            (baseline_index, baseline.ant1_index, baseline.ant2_index)
+        })
+        .collect();
+
+        let mut metafits_rec_chan_numbers: Vec<usize> = metafits_context
+            .get_expected_coarse_channels(context.corr_version)?
+            .iter()
+            .map(|coarse_chan| coarse_chan.rec_chan_number)
+            .collect();
+        metafits_rec_chan_numbers.sort_unstable();
+
+        let flagged_ants = metafits_context
+            .antennas
+            .iter()
+            .map(|antenna| {
+                flagging.flag_tiles && (antenna.rfinput_x.flagged || antenna.rfinput_y.flagged)
+            })
+            .collect();
+        let tile_names = metafits_context
+            .antennas
+            .iter()
+            .map(|antenna| Arc::from(antenna.tile_name.as_str()))
+            .collect();
+
+        Ok(VisPlan {
+            coarse_chans: resolve_indices(
+                &selection.coarse_chans,
+                context.num_coarse_chans,
+                "coarse channel",
+            )?,
+            timesteps: resolve_indices(&selection.timesteps, context.num_timesteps, "timestep")?,
+            baselines,
+            fine_chans: resolve_indices(
+                &selection.fine_chans,
+                metafits_context.num_corr_fine_chans_per_coarse,
+                "fine channel",
+            )?,
+            drop_flagged: selection.drop_flagged,
+            corrections,
+            flagging,
+            metafits_rec_chan_numbers,
+            flagged_ants,
+            tile_names,
+        })
+    }
+
+    /// The selected (coarse channel, timestep) pairs, ordered by coarse
+    /// channel, then timestep.
+    pub fn jobs(&self) -> Vec<(usize, usize)> {
+        self.coarse_chans
+            .iter()
+            .flat_map(|&coarse_chan_index| {
+                self.timesteps
+                    .iter()
+                    .map(move |&timestep_index| (coarse_chan_index, timestep_index))
+            })
+            .collect()
+    }
+
+    /// Whether a timestep starting at `unix_time_ms` is flagged for being
+    /// before the metafits good time.
+    fn quacked(&self, unix_time_ms: u64, good_time_unix_ms: u64) -> bool {
+        self.flagging.flag_quack && unix_time_ms < good_time_unix_ms
+    }
+
+    /// Read and correct one timestep of one coarse channel.
+    pub fn read_timestep(
+        &self,
+        context: &mut CorrelatorContext,
+        coarse_chan_index: usize,
+        timestep_index: usize,
+    ) -> Result<TimestepVis<'_>, ScratchpadError> {
+        let coarse_chan = context.coarse_chans[coarse_chan_index].clone();
+        let timestep = context.timesteps[timestep_index].clone();
+        let fine_chan_freqs_hz = fine_chan_freqs_hz(&coarse_chan, &context.metafits_context);
+        // Geometry is computed at the centre of the integration
+        let centroid_unix_time_ms =
+            timestep.unix_time_ms + context.metafits_context.corr_int_time_ms / 2;
+        let quacked = self.quacked(
+            timestep.unix_time_ms,
+            context.metafits_context.good_time_unix_ms,
+        );
+
+        debug!(
+            "Reading coarse chan: {} ({}) {:.3} Mhz, timestep {} ({:?})",
+            coarse_chan_index,
+            coarse_chan.rec_chan_number,
+            coarse_chan.chan_centre_hz as f32 / 1.0e6,
+            timestep_index,
+            timestep
+        );
+        // Every record of a quacked timestep would be dropped, so don't read it
+        let img_buffer = if quacked && self.drop_flagged {
+            vec![]
+        } else {
+            let mut img_buffer = context
+                .read_by_baseline(timestep_index, coarse_chan_index)
+                .map_err(MwalibError::from)?;
+            self.correct(
+                context,
+                &mut img_buffer,
+                coarse_chan.rec_chan_number,
+                &fine_chan_freqs_hz,
+                centroid_unix_time_ms,
+            )?;
+            img_buffer
+        };
+
+        Ok(TimestepVis {
+            plan: self,
+            coarse_chan: coarse_chan_index,
+            timestep: timestep_index,
+            gps_time_ms: timestep.gps_time_ms,
+            unix_time_ms: timestep.unix_time_ms,
+            centroid_unix_time_ms,
+            int_time_ms: context.metafits_context.corr_int_time_ms,
+            floats_per_finechan: context.metafits_context.num_visibility_pols * 2,
+            num_fine_chans_per_coarse: context.metafits_context.num_corr_fine_chans_per_coarse,
+            quacked,
+            fine_chan_freqs_hz,
+            img_buffer,
+        })
+    }
+
+    fn correct(
+        &self,
+        context: &CorrelatorContext,
+        img_buffer: &mut [f32],
+        rec_chan_number: usize,
+        fine_chan_freqs_hz: &[f64],
+        centroid_unix_time_ms: u64,
+    ) -> Result<(), ScratchpadError> {
+        let corrections = &self.corrections;
+        if corrections.digital_gains {
+            correct_digital_gains(
+                &context.metafits_context,
+                img_buffer,
+                &self.metafits_rec_chan_numbers,
+                rec_chan_number,
+            )?;
+        }
+        if let Some(passband) = &corrections.passband {
+            correct_passband(&context.metafits_context, img_buffer, passband);
+        }
+        if corrections.cable_delay {
+            correct_cable_lengths(&context.metafits_context, img_buffer, fine_chan_freqs_hz);
+        }
+        if let Some(phase_centre) = corrections.phase_centre {
+            rotate_phase_centre(
+                &context.metafits_context,
+                img_buffer,
+                fine_chan_freqs_hz,
+                phase_centre,
+                centroid_unix_time_ms,
+            );
+        }
+        Ok(())
+    }
+
+    /// Read every selected record, a timestep of a coarse channel at a time.
+    pub fn records<'a>(&'a self, context: &'a mut CorrelatorContext) -> VisRecords<'a> {
+        VisRecords {
+            plan: self,
+            context,
+            jobs: self.jobs().into_iter(),
+            current: None,
+            position: 0,
+        }
+    }
+}
+
+/// The corrected visibilities of one timestep of one coarse channel.
+pub struct TimestepVis<'a> {
+    plan: &'a VisPlan,
+    pub coarse_chan: usize,
+    pub timestep: usize,
+    pub gps_time_ms: u64,
+    pub unix_time_ms: u64,
+    /// The centre of the integration, where geometry is computed.
+    pub centroid_unix_time_ms: u64,
+    int_time_ms: u64,
+    floats_per_finechan: usize,
+    num_fine_chans_per_coarse: usize,
+    quacked: bool,
+    fine_chan_freqs_hz: Vec<f64>,
+    img_buffer: Vec<f32>,
+}
+
+impl<'a> TimestepVis<'a> {
+    /// The number of positions (selected baseline × fine channel) to visit.
+    fn num_positions(&self) -> usize {
+        self.plan.baselines.len() * self.plan.fine_chans.len()
+    }
+
+    /// The record at a position, unless it's flagged and being dropped.
+    fn record(&self, position: usize) -> Option<VisRecord> {
+        let plan = self.plan;
+        let (baseline, ant1, ant2) = plan.baselines[position / plan.fine_chans.len()];
+        let fine_chan = plan.fine_chans[position % plan.fine_chans.len()];
+        let flagged = self.quacked
+            || plan.flagged_ants[ant1]
+            || plan.flagged_ants[ant2]
+            || plan
+                .flagging
+                .mwafs
+                .get(self.coarse_chan)
+                .is_some_and(|mwaf| {
+                    mwaf.as_ref().is_some_and(|mwaf| {
+                        mwaf.is_flagged(self.gps_time_ms, self.int_time_ms, baseline, fine_chan)
+                    })
+                });
+        if flagged && plan.drop_flagged {
+            return None;
+        }
+        let offset =
+            (baseline * self.num_fine_chans_per_coarse + fine_chan) * self.floats_per_finechan;
+        let pol = |pol_index: usize| {
+            Complex::new(
+                self.img_buffer[offset + pol_index * 2],
+                self.img_buffer[offset + pol_index * 2 + 1],
+            )
+        };
+        Some(VisRecord {
+            coarse_chan: self.coarse_chan,
+            timestep: self.timestep,
+            baseline,
+            ant1,
+            ant2,
+            ant1_name: plan.tile_names[ant1].clone(),
+            ant2_name: plan.tile_names[ant2].clone(),
+            fine_chan,
+            freq_hz: self.fine_chan_freqs_hz[fine_chan],
+            gps_time_ms: self.gps_time_ms,
+            unix_time_ms: self.unix_time_ms,
+            vis: [pol(0), pol(1), pol(2), pol(3)],
+            flagged,
+        })
+    }
+
+    /// The first record at or after `position`, which is moved past it.
+    fn next_record(&self, position: &mut usize) -> Option<VisRecord> {
+        while *position < self.num_positions() {
+            *position += 1;
+            if let Some(record) = self.record(*position - 1) {
+                return Some(record);
+            }
+        }
+        None
+    }
+
+    /// Every selected record, ordered by baseline, then fine channel.
+    pub fn records(&self) -> impl Iterator<Item = VisRecord> + '_ {
+        (0..self.num_positions()).filter_map(move |position| self.record(position))
+    }
+}
+
+/// An iterator over every selected record of an observation, from
+/// `VisPlan::records`.
+pub struct VisRecords<'a> {
+    plan: &'a VisPlan,
+    context: &'a mut CorrelatorContext,
+    jobs: std::vec::IntoIter<(usize, usize)>,
+    current: Option<TimestepVis<'a>>,
+    position: usize,
+}
+
+impl<'a> Iterator for VisRecords<'a> {
+    type Item = Result<VisRecord, ScratchpadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &self.current {
+                if let Some(record) = current.next_record(&mut self.position) {
+                    return Some(Ok(record));
+                }
+            }
+            let (coarse_chan_index, timestep_index) = self.jobs.next()?;
+            self.position = 0;
+            match self
+                .plan
+                .read_timestep(self.context, coarse_chan_index, timestep_index)
+            {
+                Ok(current) => self.current = Some(current),
+                Err(err) => {
+                    self.current = None;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flag::FlagMask;
+
+    const NUM_ANTS: usize = 3;
+    const NUM_FINE_CHANS: usize = 4;
+    const GPS_TIME_MS: u64 = 1_065_880_130_000;
+    const INT_TIME_MS: u64 = 2000;
+
+    /// A plan for 3 antennas (6 baselines) and 4 fine channels, as
+    /// `VisPlan::new` would resolve it.
+    fn test_plan(selection: VisSelection, flagging: VisFlagging) -> VisPlan {
+        let all_baselines: Vec<(usize, usize, usize)> = (0..NUM_ANTS)
+            .flat_map(|ant1| (ant1..NUM_ANTS).map(move |ant2| (ant1, ant2)))
+            .enumerate()
+            .map(|(baseline, (ant1, ant2))| (baseline, ant1, ant2))
+            .collect();
+        let num_baselines = all_baselines.len();
+        VisPlan {
+            coarse_chans: resolve_indices(&selection.coarse_chans, 2, "coarse channel").unwrap(),
+            timesteps: resolve_indices(&selection.timesteps, 3, "timestep").unwrap(),
+            baselines: resolve_indices(&selection.baselines, num_baselines, "baseline")
+                .unwrap()
+                .into_iter()
+                .map(|baseline| all_baselines[baseline])
+                .collect(),
+            fine_chans: resolve_indices(&selection.fine_chans, NUM_FINE_CHANS, "fine channel")
+                .unwrap(),
+            drop_flagged: selection.drop_flagged,
+            corrections: VisCorrections::default(),
+            // Antenna 2 is flagged in the metafits
+            flagged_ants: vec![false, false, flagging.flag_tiles],
+            flagging,
+            metafits_rec_chan_numbers: vec![109, 110],
+            tile_names: vec![
+                Arc::from("Tile011"),
+                Arc::from("Tile012"),
+                Arc::from("Tile013"),
+            ],
+        }
+    }
+
+    /// The value of the real part of a sample, which the imaginary part is the
+    /// negative of.
+    fn test_value(baseline: usize, fine_chan: usize, pol: usize) -> f32 {
+        (baseline * 100 + fine_chan * 10 + pol) as f32
+    }
+
+    /// A synthetic [baseline][fine_chan][pol][re,im] buffer of every baseline.
+    fn test_img_buffer() -> Vec<f32> {
+        let num_baselines = NUM_ANTS * (NUM_ANTS + 1) / 2;
+        let mut img_buffer = vec![];
+        for baseline in 0..num_baselines {
+            for fine_chan in 0..NUM_FINE_CHANS {
+                for pol in 0..4 {
+                    let value = test_value(baseline, fine_chan, pol);
+                    img_buffer.extend([value, -value]);
+                }
+            }
+        }
+        img_buffer
+    }
+
+    fn test_timestep_vis(plan: &VisPlan, quacked: bool, img_buffer: Vec<f32>) -> TimestepVis<'_> {
+        TimestepVis {
+            plan,
+            coarse_chan: 1,
+            timestep: 1,
+            gps_time_ms: GPS_TIME_MS,
+            unix_time_ms: GPS_TIME_MS + 315_964_782_000,
+            centroid_unix_time_ms: GPS_TIME_MS + 315_964_782_000 + INT_TIME_MS / 2,
+            int_time_ms: INT_TIME_MS,
+            floats_per_finechan: 8,
+            num_fine_chans_per_coarse: NUM_FINE_CHANS,
+            quacked,
+            fine_chan_freqs_hz: (0..NUM_FINE_CHANS)
+                .map(|fine_chan| 139.0e6 + fine_chan as f64 * 40.0e3)
+                .collect(),
+            img_buffer,
+        }
+    }
+
+    /// (baseline, fine channel, flagged) of each record.
+    fn positions(timestep_vis: &TimestepVis) -> Vec<(usize, usize, bool)> {
+        timestep_vis
+            .records()
+            .map(|record| (record.baseline, record.fine_chan, record.flagged))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_indices() {
+        assert_eq!(resolve_indices(&[], 3, "timestep").unwrap(), vec![0, 1, 2]);
+        assert_eq!(
+            resolve_indices(&[2, 0, 2], 3, "timestep").unwrap(),
+            vec![0, 2]
+        );
+        assert!(matches!(
+            resolve_indices(&[1, 3], 3, "timestep"),
+            Err(ScratchpadError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_jobs_order() {
+        let plan = test_plan(VisSelection::default(), VisFlagging::default());
+        assert_eq!(
+            plan.jobs(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+
+        let selection = VisSelection {
+            coarse_chans: vec![1],
+            timesteps: vec![2, 0],
+            ..Default::default()
+        };
+        let plan = test_plan(selection, VisFlagging::default());
+        assert_eq!(plan.jobs(), vec![(1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_records() {
+        let plan = test_plan(VisSelection::default(), VisFlagging::default());
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        let records: Vec<VisRecord> = timestep_vis.records().collect();
+        assert_eq!(records.len(), 6 * NUM_FINE_CHANS);
+
+        // Ordered by baseline, then fine channel
+        let record = &records[4 * NUM_FINE_CHANS + 2];
+        assert_eq!((record.baseline, record.fine_chan), (4, 2));
+        assert_eq!((record.ant1, record.ant2), (1, 2));
+        assert_eq!(
+            (record.ant1_name.as_ref(), record.ant2_name.as_ref()),
+            ("Tile012", "Tile013")
+        );
+        assert_eq!((record.coarse_chan, record.timestep), (1, 1));
+        assert_eq!(record.freq_hz, 139.08e6);
+        assert_eq!(record.gps_time_ms, GPS_TIME_MS);
+        for (pol, vis) in record.vis.iter().enumerate() {
+            let value = test_value(4, 2, pol);
+            assert_eq!(*vis, Complex::new(value, -value));
+        }
+        assert!(records.iter().all(|record| !record.flagged));
+    }
+
+    #[test]
+    fn test_records_selection() {
+        let selection = VisSelection {
+            baselines: vec![4, 1],
+            fine_chans: vec![3, 0],
+            ..Default::default()
+        };
+        let plan = test_plan(selection, VisFlagging::default());
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        assert_eq!(
+            positions(&timestep_vis),
+            vec![(1, 0, false), (1, 3, false), (4, 0, false), (4, 3, false)]
+        );
+        let record = timestep_vis.records().last().unwrap();
+        assert_eq!(
+            record.vis[3],
+            Complex::new(test_value(4, 3, 3), -test_value(4, 3, 3))
+        );
+    }
+
+    #[test]
+    fn test_records_flag_tiles() {
+        let selection = VisSelection {
+            fine_chans: vec![0],
+            ..Default::default()
+        };
+        let flagging = VisFlagging {
+            flag_tiles: true,
+            ..Default::default()
+        };
+        let plan = test_plan(selection.clone(), flagging.clone());
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        // Antenna 2 is flagged
+        assert_eq!(
+            positions(&timestep_vis),
+            vec![
+                (0, 0, false),
+                (1, 0, false),
+                (2, 0, true),
+                (3, 0, false),
+                (4, 0, true),
+                (5, 0, true)
+            ]
+        );
+
+        let selection = VisSelection {
+            drop_flagged: true,
+            ..selection
+        };
+        let plan = test_plan(selection, flagging);
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        assert_eq!(
+            positions(&timestep_vis),
+            vec![(0, 0, false), (1, 0, false), (3, 0, false)]
+        );
+
+        // As `VisRecords` steps through them
+        let mut position = 0;
+        let mut baselines = vec![];
+        while let Some(record) = timestep_vis.next_record(&mut position) {
+            baselines.push(record.baseline);
+        }
+        assert_eq!(baselines, vec![0, 1, 3]);
+        assert_eq!(position, 6);
+    }
+
+    #[test]
+    fn test_records_quack() {
+        let flagging = VisFlagging {
+            flag_quack: true,
+            ..Default::default()
+        };
+        let plan = test_plan(VisSelection::default(), flagging);
+        let good_time_unix_ms = 1_381_844_914_000;
+        assert!(plan.quacked(good_time_unix_ms - INT_TIME_MS, good_time_unix_ms));
+        assert!(!plan.quacked(good_time_unix_ms, good_time_unix_ms));
+        assert!(!test_plan(VisSelection::default(), VisFlagging::default())
+            .quacked(good_time_unix_ms - INT_TIME_MS, good_time_unix_ms));
+
+        let timestep_vis = test_timestep_vis(&plan, true, test_img_buffer());
+        assert_eq!(timestep_vis.records().count(), 6 * NUM_FINE_CHANS);
+        assert!(timestep_vis.records().all(|record| record.flagged));
+
+        // Quacked timesteps aren't read when they'd be dropped
+        let selection = VisSelection {
+            drop_flagged: true,
+            ..Default::default()
+        };
+        let plan = test_plan(selection, VisFlagging::default());
+        let timestep_vis = test_timestep_vis(&plan, true, vec![]);
+        assert_eq!(timestep_vis.records().count(), 0);
+    }
+
+    #[test]
+    fn test_records_mwaf() {
+        let num_baselines = NUM_ANTS * (NUM_ANTS + 1) / 2;
+        let mut mask = FlagMask::new(3, num_baselines, NUM_FINE_CHANS);
+        // The second scan of the file is this timestep
+        let index = mask.index(1, 4, 3);
+        mask.flags[index] = true;
+        let index = mask.index(0, 1, 0);
+        mask.flags[index] = true;
+        let mwaf = MwafFile {
+            gps_time_ms: GPS_TIME_MS - INT_TIME_MS,
+            gpubox_number: 110,
+            num_ants: NUM_ANTS,
+            mask,
+        };
+        let selection = VisSelection {
+            baselines: vec![1, 4],
+            fine_chans: vec![0, 3],
+            ..Default::default()
+        };
+        let flagging = VisFlagging {
+            mwafs: vec![None, Some(mwaf)],
+            ..Default::default()
+        };
+        let plan = test_plan(selection.clone(), flagging.clone());
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        assert_eq!(
+            positions(&timestep_vis),
+            vec![(1, 0, false), (1, 3, false), (4, 0, false), (4, 3, true)]
+        );
+
+        let selection = VisSelection {
+            drop_flagged: true,
+            ..selection
+        };
+        let plan = test_plan(selection, flagging);
+        let timestep_vis = test_timestep_vis(&plan, false, test_img_buffer());
+        assert_eq!(
+            positions(&timestep_vis),
+            vec![(1, 0, false), (1, 3, false), (4, 0, false)]
+        );
+    }
+}